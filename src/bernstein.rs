@@ -0,0 +1,665 @@
+use crate::Polynomial;
+
+use core::ops::{Add, Div, Mul, Sub};
+use num::{FromPrimitive, One, Zero};
+use smallvec::SmallVec;
+
+// polynomial b₀B₀,ₙ(t) + b₁B₁,ₙ(t) + … + bₙBₙ,ₙ(t) on [a, b] in the Bernstein basis
+// Bₖ,ₙ(t) = C(n, k)tᵏ(1 - t)ⁿ⁻ᵏ with t = (x - a) / (b - a)
+//
+// the basis functions are non-negative on [a, b] and sum to one, so p(x) is a convex combination of
+// the coefficients, which therefore bound the range of p on [a, b]
+//
+// unlike in the monomial basis, the coefficients are not normalized, as the degree n is part of the
+// representation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BernsteinPolynomial<T> {
+	coeffs: SmallVec<[T; 8]>,
+	a: T,
+	b: T,
+}
+
+fn lerp<T>(u: &T, v: &T, t: &T, one_minus_t: &T) -> T
+where
+	T: Add<Output = T>,
+	for<'l, 'r> &'l T: Mul<&'r T, Output = T>,
+{
+	one_minus_t * u + t * v
+}
+
+impl<T> BernsteinPolynomial<T> {
+	// coefficients b₀, b₁, …, bₙ on [a, b]
+	pub fn new(coeffs: SmallVec<[T; 8]>, a: T, b: T) -> Self {
+		assert!(!coeffs.is_empty());
+		Self { coeffs, a, b }
+	}
+
+	pub fn coeffs(&self) -> &SmallVec<[T; 8]> {
+		&self.coeffs
+	}
+
+	pub fn into_coeffs(self) -> SmallVec<[T; 8]> {
+		self.coeffs
+	}
+
+	pub fn interval(&self) -> (&T, &T) {
+		(&self.a, &self.b)
+	}
+
+	pub fn degree(&self) -> usize {
+		self.coeffs.len() - 1
+	}
+
+	fn parameter(&self, x: &T) -> (T, T)
+	where
+		T: One,
+		for<'l, 'r> &'l T: Sub<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let t = &(x - &self.a) / &(&self.b - &self.a);
+		let one_minus_t = &T::one() - &t;
+		(t, one_minus_t)
+	}
+
+	// de Casteljau's algorithm, which is numerically stable for x in [a, b]
+	pub fn eval(&self, x: &T) -> T
+	where
+		T: One + Clone + Add<Output = T>,
+		for<'l, 'r> &'l T: Sub<&'r T, Output = T> + Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let (t, one_minus_t) = self.parameter(x);
+		let mut b = self.coeffs.clone();
+		for level in (1..b.len()).rev() {
+			for i in 0..level {
+				b[i] = lerp::<T>(&b[i], &b[i + 1], &t, &one_minus_t);
+			}
+		}
+		b.swap_remove(0)
+	}
+
+	// the same polynomial on [a, x] and [x, b], whose coefficients are the first and last ones of the
+	// intermediate results of de Casteljau's algorithm
+	pub fn subdivide(&self, x: &T) -> (Self, Self)
+	where
+		T: One + Clone + Add<Output = T>,
+		for<'l, 'r> &'l T: Sub<&'r T, Output = T> + Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let (t, one_minus_t) = self.parameter(x);
+		let n = self.coeffs.len();
+		let mut b = self.coeffs.clone();
+		let mut left = SmallVec::with_capacity(n);
+		let mut right: SmallVec<[T; 8]> = SmallVec::with_capacity(n);
+		left.push(b[0].clone());
+		right.push(b[n - 1].clone());
+		for level in (1..n).rev() {
+			for i in 0..level {
+				b[i] = lerp::<T>(&b[i], &b[i + 1], &t, &one_minus_t);
+			}
+			left.push(b[0].clone());
+			right.push(b[level - 1].clone());
+		}
+		right.reverse();
+		(
+			Self::new(left, self.a.clone(), x.clone()),
+			Self::new(right, x.clone(), self.b.clone()),
+		)
+	}
+
+	// d/dx with degree n - 1: b'ₖ = n(bₖ₊₁ - bₖ) / (b - a)
+	pub fn derivative(&self) -> Self
+	where
+		T: Zero + Clone + FromPrimitive,
+		for<'l, 'r> &'l T: Sub<&'r T, Output = T> + Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let n = self.degree();
+		let coeffs = if n == 0 {
+			SmallVec::from_elem(T::zero(), 1)
+		} else {
+			let scale = &T::from_usize(n).unwrap() / &(&self.b - &self.a);
+			self.coeffs
+				.windows(2)
+				.map(|w| &scale * &(&w[1] - &w[0]))
+				.collect()
+		};
+		Self::new(coeffs, self.a.clone(), self.b.clone())
+	}
+
+	// the same polynomial with degree n + 1: b'ₖ = k / (n + 1) · bₖ₋₁ + (1 - k / (n + 1)) · bₖ
+	pub fn elevate(&self) -> Self
+	where
+		T: One + Clone + FromPrimitive + Add<Output = T>,
+		for<'l, 'r> &'l T: Sub<&'r T, Output = T> + Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let n = self.coeffs.len();
+		let n1 = T::from_usize(n).unwrap();
+		let mut coeffs = SmallVec::with_capacity(n + 1);
+		coeffs.push(self.coeffs[0].clone());
+		for k in 1..n {
+			let t = &T::from_usize(k).unwrap() / &n1;
+			let one_minus_t = &T::one() - &t;
+			coeffs.push(lerp::<T>(
+				&self.coeffs[k],
+				&self.coeffs[k - 1],
+				&t,
+				&one_minus_t,
+			));
+		}
+		coeffs.push(self.coeffs[n - 1].clone());
+		Self::new(coeffs, self.a.clone(), self.b.clone())
+	}
+
+	// degree n - 1 polynomial whose elevation is closest to this one by inverting the elevation
+	// formula from the left for the first half of the coefficients and from the right for the second
+	// half, see Forrest: "Interactive interpolation and approximation by Bézier polynomials" (1972)
+	// the result is exact if the polynomial has lower degree and interpolates p(a) and p(b) otherwise,
+	// except for degree 1, where it is the constant (p(a) + p(b)) / 2
+	pub fn reduce(&self) -> Self
+	where
+		T: Clone + FromPrimitive,
+		for<'l, 'r> &'l T: Add<&'r T, Output = T>
+			+ Sub<&'r T, Output = T>
+			+ Mul<&'r T, Output = T>
+			+ Div<&'r T, Output = T>,
+	{
+		let n = self.degree();
+		assert!(n >= 1);
+		let from = |k: usize| T::from_usize(k).unwrap();
+		if n == 1 {
+			let mean = &(&self.coeffs[0] + &self.coeffs[1]) / &from(2);
+			let coeffs = core::iter::once(mean).collect();
+			return Self::new(coeffs, self.a.clone(), self.b.clone());
+		}
+		let nt = from(n);
+		// bₖ = k / n · cₖ₋₁ + (n - k) / n · cₖ for k = 0, …, n
+		let split = n / 2;
+		let mut coeffs: SmallVec<[T; 8]> = SmallVec::with_capacity(n);
+		coeffs.push(self.coeffs[0].clone());
+		for k in 1..split {
+			let c = &(&(&nt * &self.coeffs[k]) - &(&from(k) * &coeffs[k - 1])) / &from(n - k);
+			coeffs.push(c);
+		}
+		let mut tail: SmallVec<[T; 8]> = SmallVec::with_capacity(n);
+		tail.push(self.coeffs[n].clone());
+		// cₖ₋₁ for k = n - 1, …, until the coefficients computed from the left are reached
+		for k in (coeffs.len() + 1..n).rev() {
+			let c = &(&(&nt * &self.coeffs[k]) - &(&from(n - k) * tail.last().unwrap())) / &from(k);
+			tail.push(c);
+		}
+		coeffs.extend(tail.into_iter().rev());
+		Self::new(coeffs, self.a.clone(), self.b.clone())
+	}
+
+	// the convex hull of the coefficients, which contains p(x) for all x in [a, b]
+	pub fn range(&self) -> (T, T)
+	where
+		T: Clone + PartialOrd,
+	{
+		let mut lo = &self.coeffs[0];
+		let mut hi = lo;
+		for c in self.coeffs.iter().skip(1) {
+			if c < lo {
+				lo = c;
+			}
+			if c > hi {
+				hi = c;
+			}
+		}
+		(lo.clone(), hi.clone())
+	}
+
+	// expands Σ bₖC(n, k)tᵏ(1 - t)ⁿ⁻ᵏ into Σ aⱼtʲ with aⱼ = C(n, j)Δʲb₀ using forward differences, and
+	// substitutes t = (x - a) / (b - a)
+	pub fn to_polynomial(&self) -> Polynomial<T>
+	where
+		T: Zero + Clone + FromPrimitive + Add<Output = T>,
+		for<'l, 'r> &'l T: Sub<&'r T, Output = T> + Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let n = self.degree();
+		let width = &self.b - &self.a;
+		let mut differences = self.coeffs.clone();
+		let mut rev_coeffs: SmallVec<[T; 8]> = SmallVec::with_capacity(n + 1);
+		let mut binomial = T::from_usize(1).unwrap();
+		let mut scale = binomial.clone();
+		for j in 0..=n {
+			if j > 0 {
+				for i in 0..=n - j {
+					differences[i] = &differences[i + 1] - &differences[i];
+				}
+				binomial =
+					&(&binomial * &T::from_usize(n + 1 - j).unwrap()) / &T::from_usize(j).unwrap();
+				scale = &scale * &width;
+			}
+			rev_coeffs.push(&(&binomial * &differences[0]) / &scale);
+		}
+		// p(x) = q(x - a), expanded as in Polynomial::taylor_expand
+		let shift = &T::zero() - &self.a;
+		for i in 0..n {
+			for j in (i..n).rev() {
+				rev_coeffs[j] = rev_coeffs[j].clone() + &shift * &rev_coeffs[j + 1];
+			}
+		}
+		Polynomial::new_reversed(rev_coeffs)
+	}
+}
+
+impl<T> Polynomial<T> {
+	// the representation with degree n = max(order, 0) on [a, b]: substitutes x = a + (b - a)t and
+	// converts Σ aⱼtʲ using bₖ = Σⱼ C(k, j) / C(n, j) · aⱼ
+	pub fn to_bernstein(&self, a: T, b: T) -> BernsteinPolynomial<T>
+	where
+		T: Clone + FromPrimitive + Add<Output = T>,
+		for<'l, 'r> &'l T: Sub<&'r T, Output = T> + Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let mut coeffs = self.rev_coeffs.clone();
+		let n = coeffs.len() - 1;
+		for i in 0..n {
+			for j in (i..n).rev() {
+				coeffs[j] = coeffs[j].clone() + &a * &coeffs[j + 1];
+			}
+		}
+
+		let width = &b - &a;
+		let mut scale = T::from_usize(1).unwrap();
+		let mut binomial = scale.clone();
+		for (j, c) in coeffs.iter_mut().enumerate().skip(1) {
+			scale = &scale * &width;
+			binomial =
+				&(&binomial * &T::from_usize(n + 1 - j).unwrap()) / &T::from_usize(j).unwrap();
+			*c = &(&*c * &scale) / &binomial;
+		}
+		// partial sums with binomial weights, computed by summing n times
+		for j in 1..=n {
+			for k in (j..=n).rev() {
+				coeffs[k] = coeffs[k].clone() + coeffs[k - 1].clone();
+			}
+		}
+		BernsteinPolynomial::new(coeffs, a, b)
+	}
+}
+
+// an interval [lo, hi] isolating real roots of a polynomial, where lo = hi for exact roots
+//
+// a certified interval contains at least one real root, which is the case if it was isolated by a
+// single sign change in the coefficients, if the polynomial vanishes exactly or if it has opposite
+// signs at its ends, all with respect to the computed coefficients; otherwise it is a cluster that
+// could not be resolved at the requested tolerance, which may contain an even number of roots, such
+// as a double root, or none at all if it is caused by complex conjugate roots near the real axis
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RootInterval {
+	pub lo: f64,
+	pub hi: f64,
+	pub certified: bool,
+}
+
+// the number of sign changes in the coefficients, ignoring zeros, which is an upper bound with the
+// same parity for the number of roots in the open interval (a, b) by Descartes' rule of signs
+fn sign_changes(coeffs: &[f64]) -> usize {
+	let mut changes = 0;
+	let mut previous = 0.0;
+	for &c in coeffs.iter().filter(|&&c| c != 0.0) {
+		if (c < 0.0) != (previous < 0.0) && previous != 0.0 {
+			changes += 1;
+		}
+		previous = c;
+	}
+	changes
+}
+
+fn opposite_signs(x: f64, y: f64) -> bool {
+	(x < 0.0 && y > 0.0) || (x > 0.0 && y < 0.0)
+}
+
+impl BernsteinPolynomial<f64> {
+	// the parameter interval [t₀, t₁] ⊆ [0, 1] in which the convex hull of the control points
+	// (k / n, bₖ) meets the t-axis, computed as the hull of its intersections with all segments
+	// between pairs of control points, None if it is disjoint from the axis
+	fn clip(&self) -> Option<(f64, f64)> {
+		let n = self.degree();
+		if n == 0 {
+			return None;
+		}
+		let t = |k: usize| k as f64 / n as f64;
+		let (mut lo, mut hi) = (f64::INFINITY, f64::NEG_INFINITY);
+		for (i, &bi) in self.coeffs.iter().enumerate() {
+			if bi == 0.0 {
+				lo = lo.min(t(i));
+				hi = hi.max(t(i));
+				continue;
+			}
+			for (j, &bj) in self.coeffs.iter().enumerate().skip(i + 1) {
+				if bj != 0.0 && (bi < 0.0) != (bj < 0.0) {
+					let x = t(i) - bi * (t(j) - t(i)) / (bj - bi);
+					lo = lo.min(x);
+					hi = hi.max(x);
+				}
+			}
+		}
+		if lo <= hi {
+			Some((lo.max(0.0), hi.min(1.0)))
+		} else {
+			None
+		}
+	}
+
+	// an interval of width at most tolerance containing the single root in (a, b) of a polynomial
+	// with one sign change, using Bézier clipping and falling back to bisection when clipping removes
+	// less than half of the interval
+	fn refine_root(&self, tolerance: f64) -> (f64, f64) {
+		let n = self.degree();
+		let mut p = self.clone();
+		while p.b - p.a > tolerance {
+			let width = p.b - p.a;
+			let clipped = p.clip().and_then(|(t0, t1)| {
+				let (lo, hi) = (p.a + t0 * width, p.a + t1 * width);
+				if hi - lo > width / 2.0 {
+					return None;
+				}
+				if hi - lo <= tolerance {
+					return Some(Err((lo, hi)));
+				}
+				let q = if lo > p.a {
+					p.subdivide(&lo).1
+				} else {
+					p.clone()
+				};
+				let q = if hi < q.b { q.subdivide(&hi).0 } else { q };
+				// rounding may move the root out of the clipped interval
+				if sign_changes(&q.coeffs) == 1 {
+					Some(Ok(q))
+				} else {
+					None
+				}
+			});
+			p = match clipped {
+				Some(Ok(q)) => q,
+				Some(Err(interval)) => return interval,
+				None => {
+					let mid = p.a + width / 2.0;
+					if mid <= p.a || mid >= p.b {
+						break;
+					}
+					let (left, right) = p.subdivide(&mid);
+					if left.coeffs[n] == 0.0 {
+						return (mid, mid);
+					}
+					if sign_changes(&left.coeffs) == 1 {
+						left
+					} else {
+						right
+					}
+				}
+			};
+		}
+		(p.a, p.b)
+	}
+
+	// disjoint intervals in ascending order isolating the real roots in [a, b], which are found by
+	// subdividing until each part has at most one sign change in its coefficients, and then refined
+	// by Bézier clipping, see Sederberg, Nishita: "Curve intersection using Bézier clipping" (1990)
+	//
+	// each interval has a width of at most tolerance, unless touching intervals were merged, such as
+	// a cluster of roots that cannot be separated at that resolution; the zero polynomial has no
+	// isolated roots
+	pub fn root_intervals(&self, tolerance: f64) -> Vec<RootInterval> {
+		assert!(tolerance > 0.0 && self.a < self.b);
+		let n = self.degree();
+		let mut intervals: Vec<RootInterval> = Vec::new();
+		if self.coeffs.iter().all(|&c| c == 0.0) {
+			return intervals;
+		}
+
+		// p at the left end of the last interval, if that is an uncertified cluster
+		let mut cluster_start = 0.0;
+		let mut push =
+			|lo: f64, hi: f64, start: f64, end: f64, certified: bool| match intervals.last_mut() {
+				Some(last) if lo <= last.hi => {
+					last.hi = last.hi.max(hi);
+					last.certified |= certified || opposite_signs(cluster_start, end);
+				}
+				_ => {
+					cluster_start = start;
+					intervals.push(RootInterval { lo, hi, certified });
+				}
+			};
+
+		// Descartes' rule of signs only covers the open interval, roots at the left ends of parts are
+		// checked before they are processed, except for left halves which share it with their parent
+		let mut parts = vec![(self.clone(), true)];
+		while let Some((p, check_start)) = parts.pop() {
+			if check_start && p.coeffs[0] == 0.0 {
+				push(p.a, p.a, 0.0, 0.0, true);
+			}
+			let mid = p.a + (p.b - p.a) / 2.0;
+			match sign_changes(&p.coeffs) {
+				0 => {}
+				1 => {
+					let (lo, hi) = p.refine_root(tolerance);
+					push(lo, hi, 0.0, 0.0, true);
+				}
+				_ if p.b - p.a <= tolerance || mid <= p.a || mid >= p.b => {
+					let (start, end) = (p.coeffs[0], p.coeffs[n]);
+					push(p.a, p.b, start, end, opposite_signs(start, end));
+				}
+				_ => {
+					let (left, right) = p.subdivide(&mid);
+					parts.push((right, true));
+					parts.push((left, false));
+				}
+			}
+		}
+		if self.coeffs[n] == 0.0 && n > 0 {
+			push(self.b, self.b, 0.0, 0.0, true);
+		}
+		intervals
+	}
+
+	// the midpoints of the intervals isolating the real roots in [a, b], including those of
+	// uncertified clusters, see root_intervals
+	pub fn roots(&self, tolerance: f64) -> Vec<f64> {
+		self.root_intervals(tolerance)
+			.iter()
+			.map(|interval| interval.lo + (interval.hi - interval.lo) / 2.0)
+			.collect()
+	}
+}
+
+impl Polynomial<f64> {
+	// the intervals isolating the real roots in [a, b] using BernsteinPolynomial::root_intervals
+	pub fn real_root_intervals(&self, a: f64, b: f64, tolerance: f64) -> Vec<RootInterval> {
+		self.to_bernstein(a, b).root_intervals(tolerance)
+	}
+
+	// the real roots in [a, b] using BernsteinPolynomial::roots
+	pub fn real_roots(&self, a: f64, b: f64, tolerance: f64) -> Vec<f64> {
+		self.to_bernstein(a, b).roots(tolerance)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use num::rational::Rational64;
+
+	fn r(n: i64) -> Rational64 {
+		Rational64::from_integer(n)
+	}
+
+	#[test]
+	fn test_conversion() {
+		// x³ - 2x² + 3x - 5 on [1, 3]
+		let poly = Polynomial::new(coefficients![r(1), r(-2), r(3), r(-5)]);
+		let bernstein = poly.to_bernstein(r(1), r(3));
+		assert_eq!(bernstein.degree(), 3);
+		assert_eq!(bernstein.coeffs()[0], poly.eval(r(1)));
+		assert_eq!(bernstein.coeffs()[3], poly.eval(r(3)));
+		assert_eq!(bernstein.to_polynomial(), poly);
+		for k in -4..=8 {
+			let x = Rational64::new(k, 2);
+			assert_eq!(bernstein.eval(&x), poly.eval(x));
+		}
+
+		// t(1 - t) on [0, 1]
+		let bernstein =
+			BernsteinPolynomial::new(coefficients![r(0), Rational64::new(1, 2), r(0)], r(0), r(1));
+		assert_eq!(
+			bernstein.to_polynomial(),
+			Polynomial::new(coefficients![r(-1), r(1), r(0)])
+		);
+
+		let constant = Polynomial::new(coefficients![r(7)]).to_bernstein(r(-1), r(1));
+		assert_eq!(constant.coeffs(), &coefficients![r(7)]);
+	}
+
+	#[test]
+	fn test_subdivide() {
+		let poly = Polynomial::new(coefficients![r(2), r(-1), r(0), r(3), r(1)]);
+		let bernstein = poly.to_bernstein(r(-1), r(2));
+		let (left, right) = bernstein.subdivide(&Rational64::new(1, 3));
+		assert_eq!(left.interval(), (&r(-1), &Rational64::new(1, 3)));
+		assert_eq!(right.interval(), (&Rational64::new(1, 3), &r(2)));
+		assert_eq!(left, poly.to_bernstein(r(-1), Rational64::new(1, 3)));
+		assert_eq!(right, poly.to_bernstein(Rational64::new(1, 3), r(2)));
+
+		assert_eq!(
+			bernstein.derivative().to_polynomial(),
+			poly.derivative(1).unwrap()
+		);
+	}
+
+	#[test]
+	fn test_elevate_reduce() {
+		let bernstein =
+			BernsteinPolynomial::new(coefficients![r(1), r(-2), r(4), r(3)], r(0), r(2));
+		for _ in 0..3 {
+			let elevated = bernstein.elevate();
+			assert_eq!(elevated.degree(), 4);
+			assert_eq!(elevated.to_polynomial(), bernstein.to_polynomial());
+			assert_eq!(elevated.reduce(), bernstein);
+		}
+		let mut elevated = bernstein.clone();
+		for _ in 0..4 {
+			elevated = elevated.elevate();
+		}
+		for _ in 0..4 {
+			elevated = elevated.reduce();
+		}
+		assert_eq!(elevated, bernstein);
+
+		// t² cannot be represented with degree 1, but the endpoints are interpolated
+		let square = BernsteinPolynomial::new(coefficients![0f64, 0.0, 1.0], 0.0, 1.0);
+		assert_eq!(square.reduce().coeffs(), &coefficients![0f64, 1.0]);
+
+		// a line is reduced to its mean value, which is exact for a constant
+		let line = BernsteinPolynomial::new(coefficients![r(1), r(4)], r(0), r(2));
+		assert_eq!(line.reduce().coeffs(), &coefficients![r(5) / r(2)]);
+		let constant = BernsteinPolynomial::new(coefficients![r(3)], r(0), r(2));
+		assert_eq!(constant.elevate().reduce(), constant);
+	}
+
+	#[test]
+	fn test_range() {
+		// the range of x² - 2x on [0, 2] is [-1, 0], where the convex hull is [-2, 0]
+		let poly = Polynomial::new(coefficients![1f64, -2.0, 0.0]);
+		let bernstein = poly.to_bernstein(0.0, 2.0);
+		assert_eq!(bernstein.range(), (-2.0, 0.0));
+
+		// subdivision converges to the range
+		let (left, right) = bernstein.subdivide(&1.0);
+		assert_eq!(left.range(), (-1.0, 0.0));
+		assert_eq!(right.range(), (-1.0, 0.0));
+
+		let poly = Polynomial::new(coefficients![0.5f64, -1.25, 3.0, 1.0]);
+		let (lo, hi) = poly.to_bernstein(-2.0, 1.5).range();
+		for k in 0..=100 {
+			let y = poly.eval(-2.0 + 3.5 * f64::from(k) / 100.0);
+			assert!(lo - 1e-12 <= y && y <= hi + 1e-12);
+		}
+	}
+
+	#[test]
+	fn test_roots() {
+		// (x + 1)(x - 1/3)(x - 1/2)(x - 2)
+		let poly = Polynomial::new(coefficients![6.0, -11.0, -6.0, 9.0, -2.0]);
+		let intervals = poly.real_root_intervals(-3.0, 3.0, 1e-14);
+		assert!(intervals.iter().all(|interval| interval.certified));
+		assert!(intervals.windows(2).all(|w| w[0].hi < w[1].lo));
+		let roots = poly.real_roots(-3.0, 3.0, 1e-14);
+		let expected = [-1.0, 1.0 / 3.0, 0.5, 2.0];
+		assert_eq!(roots.len(), 4);
+		for (x, e) in roots.iter().zip(expected.iter()) {
+			assert!((x - e).abs() < 1e-13);
+		}
+
+		// only the roots inside the interval, where the subdivision point 0.5 is a root
+		let roots = poly.real_roots(0.0, 1.0, 1e-14);
+		assert_eq!(roots.len(), 2);
+		assert!((roots[0] - 1.0 / 3.0).abs() < 1e-13 && (roots[1] - 0.5).abs() < 1e-13);
+
+		// roots at the endpoints are found if the coefficients vanish exactly
+		let bernstein = BernsteinPolynomial::new(coefficients![0.0, -1.0, 2.0, 0.0], 0.0, 1.0);
+		let roots = bernstein.roots(1e-14);
+		assert_eq!(roots.len(), 3);
+		assert_eq!((roots[0], roots[2]), (0.0, 1.0));
+		assert!(bernstein.eval(&roots[1]).abs() < 1e-15);
+
+		// x² + 1 has no real roots, and constants have none either
+		let poly = Polynomial::new(coefficients![1.0, 0.0, 1.0]);
+		assert!(poly.real_roots(-10.0, 10.0, 1e-12).is_empty());
+		assert!(Polynomial::new(coefficients![0.0])
+			.real_roots(0.0, 1.0, 1e-12)
+			.is_empty());
+	}
+
+	#[test]
+	fn test_roots_clustered() {
+		// Wilkinson's polynomial with roots 1, …, 20
+		let mut poly = Polynomial::new(coefficients![1.0]);
+		for k in 1..=20 {
+			poly *= Polynomial::new(coefficients![1.0, -f64::from(k)]);
+		}
+		let intervals = poly.to_bernstein(0.5, 20.5).root_intervals(1e-10);
+		assert!(intervals.iter().all(|interval| interval.certified));
+		let roots = poly.to_bernstein(0.5, 20.5).roots(1e-10);
+		assert_eq!(roots.len(), 20);
+		// the roots are ill-conditioned with respect to the rounding of the coefficients
+		for (k, x) in (1..=20).zip(roots.iter()) {
+			assert!((x - f64::from(k)).abs() < 0.1);
+		}
+
+		// close simple roots are separated
+		let poly = Polynomial::new(coefficients![1.0, -1.0, 0.2475]);
+		let intervals = poly.real_root_intervals(0.0, 1.0, 1e-14);
+		assert_eq!(intervals.len(), 2);
+		assert!(intervals.iter().all(|interval| interval.certified));
+		let roots = poly.real_roots(0.0, 1.0, 1e-14);
+		assert!((roots[0] - 0.45).abs() < 1e-12 && (roots[1] - 0.55).abs() < 1e-12);
+
+		// a double root is found exactly at a subdivision point, otherwise it is reported as a single
+		// uncertified cluster, while a cluster around a triple root is certified
+		let poly = Polynomial::new(coefficients![1.0, -1.0, 0.25]);
+		let intervals = poly.real_root_intervals(0.0, 1.0, 1e-9);
+		assert_eq!(
+			intervals,
+			[RootInterval {
+				lo: 0.5,
+				hi: 0.5,
+				certified: true
+			}]
+		);
+		let poly = Polynomial::new(coefficients![1.0, -0.2, 0.01]);
+		let intervals = poly.real_root_intervals(0.0, 1.0, 1e-4);
+		assert_eq!(intervals.len(), 1);
+		assert!(!intervals[0].certified && intervals[0].lo <= 0.1 && 0.1 <= intervals[0].hi);
+		let poly = Polynomial::new(coefficients![1.0, -0.3, 0.03, -0.001]);
+		let intervals = poly.real_root_intervals(0.0, 1.0, 1e-4);
+		assert_eq!(intervals.len(), 1);
+		assert!(intervals[0].certified && intervals[0].lo <= 0.1 && 0.1 <= intervals[0].hi);
+
+		// the roots 0.3 ± 10⁻⁵i form an uncertified cluster at a coarser tolerance, as there is no
+		// real root
+		let poly = Polynomial::new(coefficients![1.0, -0.6, 0.09 + 1e-10]);
+		let intervals = poly.real_root_intervals(0.0, 1.0, 1e-4);
+		assert_eq!(intervals.len(), 1);
+		assert!(!intervals[0].certified && intervals[0].lo <= 0.3 && 0.3 <= intervals[0].hi);
+		assert!(poly.real_root_intervals(0.0, 1.0, 1e-6).is_empty());
+	}
+}
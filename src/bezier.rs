@@ -0,0 +1,358 @@
+use crate::{BernsteinPolynomial, Polynomial, QuadratureRule, RootInterval};
+
+use core::convert::TryFrom;
+use core::ops::{Add, Div, Mul, Sub};
+use num::{FromPrimitive, One, Zero};
+use smallvec::SmallVec;
+
+// Bézier curve in D dimensions parametrized over [0, 1], whose coordinates are Bernstein polynomials
+// of the same degree, with the control points as coefficients
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BezierCurve<T, const D: usize> {
+	coordinates: [BernsteinPolynomial<T>; D],
+}
+
+impl<T, const D: usize> BezierCurve<T, D> {
+	pub fn new(control_points: &[[T; D]]) -> Self
+	where
+		T: Zero + One + Clone,
+	{
+		assert!(!control_points.is_empty());
+		Self {
+			coordinates: core::array::from_fn(|axis| {
+				BernsteinPolynomial::new(
+					control_points.iter().map(|p| p[axis].clone()).collect(),
+					T::zero(),
+					T::one(),
+				)
+			}),
+		}
+	}
+
+	// reparametrizes the D coordinates over [0, 1]
+	fn from_coordinates(coordinates: Vec<BernsteinPolynomial<T>>) -> Self
+	where
+		T: Zero + One,
+	{
+		let coordinates: Vec<_> = coordinates
+			.into_iter()
+			.map(|c| BernsteinPolynomial::new(c.into_coeffs(), T::zero(), T::one()))
+			.collect();
+		match <[_; D]>::try_from(coordinates) {
+			Ok(coordinates) => Self { coordinates },
+			Err(_) => unreachable!(),
+		}
+	}
+
+	pub fn control_points(&self) -> Vec<[T; D]>
+	where
+		T: Clone,
+	{
+		(0..=self.degree())
+			.map(|k| core::array::from_fn(|axis| self.coordinates[axis].coeffs()[k].clone()))
+			.collect()
+	}
+
+	pub fn coordinates(&self) -> &[BernsteinPolynomial<T>; D] {
+		&self.coordinates
+	}
+
+	pub fn degree(&self) -> usize {
+		self.coordinates[0].degree()
+	}
+
+	pub fn eval(&self, t: &T) -> [T; D]
+	where
+		T: One + Clone + Add<Output = T>,
+		for<'l, 'r> &'l T: Sub<&'r T, Output = T> + Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		core::array::from_fn(|axis| self.coordinates[axis].eval(t))
+	}
+
+	// the hodograph B'(t), a curve of degree n - 1
+	pub fn derivative(&self) -> Self
+	where
+		T: Zero + Clone + FromPrimitive,
+		for<'l, 'r> &'l T: Sub<&'r T, Output = T> + Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		Self {
+			coordinates: core::array::from_fn(|axis| self.coordinates[axis].derivative()),
+		}
+	}
+
+	// the parts for [0, t] and [t, 1], each reparametrized over [0, 1]
+	pub fn split(&self, t: &T) -> (Self, Self)
+	where
+		T: Zero + One + Clone + Add<Output = T>,
+		for<'l, 'r> &'l T: Sub<&'r T, Output = T> + Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let (left, right): (Vec<_>, Vec<_>) =
+			self.coordinates.iter().map(|c| c.subdivide(t)).unzip();
+		(Self::from_coordinates(left), Self::from_coordinates(right))
+	}
+
+	// the bounding box of the control points, which contains the curve
+	pub fn control_box(&self) -> ([T; D], [T; D])
+	where
+		T: Clone + PartialOrd,
+	{
+		let ranges: [(T, T); D] = core::array::from_fn(|axis| self.coordinates[axis].range());
+		(
+			core::array::from_fn(|axis| ranges[axis].0.clone()),
+			core::array::from_fn(|axis| ranges[axis].1.clone()),
+		)
+	}
+
+	// the coordinates as polynomials in t
+	pub fn to_polynomials(&self) -> [Polynomial<T>; D]
+	where
+		T: Zero + Clone + FromPrimitive + Add<Output = T>,
+		for<'l, 'r> &'l T: Sub<&'r T, Output = T> + Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		core::array::from_fn(|axis| self.coordinates[axis].to_polynomial())
+	}
+}
+
+impl<const D: usize> BezierCurve<f64, D> {
+	// the tight bounding box, taking the extrema of each coordinate at the endpoints and the roots of
+	// the hodograph
+	pub fn bounding_box(&self) -> ([f64; D], [f64; D]) {
+		let derivative = self.derivative();
+		let mut lo = [0.0; D];
+		let mut hi = [0.0; D];
+		for axis in 0..D {
+			let coordinate = &self.coordinates[axis];
+			let (hull_lo, hull_hi) = coordinate.range();
+			let (mut l, mut h) = (f64::INFINITY, f64::NEG_INFINITY);
+			// the parameters need not be accurate, as the coordinate is stationary at the extrema
+			let roots = derivative.coordinates[axis].roots(1e-12);
+			for t in roots.into_iter().chain([0.0, 1.0]) {
+				let x = coordinate.eval(&t);
+				l = l.min(x);
+				h = h.max(x);
+			}
+			lo[axis] = l.max(hull_lo);
+			hi[axis] = h.min(hull_hi);
+		}
+		(lo, hi)
+	}
+
+	// the length ∫ |B'(t)| dt over [0, 1] using adaptive Gauss-Legendre quadrature: intervals are
+	// bisected until the estimates for the halves agree with that for the whole interval
+	pub fn arc_length(&self, tolerance: f64) -> f64 {
+		let derivative = self.derivative();
+		let rule = QuadratureRule::gauss_legendre(8).unwrap();
+		let speed = |t: f64| {
+			derivative
+				.eval(&t)
+				.iter()
+				.map(|x| x * x)
+				.sum::<f64>()
+				.sqrt()
+		};
+		let estimate = |a: f64, b: f64| {
+			let (mid, half) = ((a + b) / 2.0, (b - a) / 2.0);
+			half * rule.integrate(|x| speed(mid + half * x))
+		};
+
+		let mut length = 0.0;
+		let mut stack = vec![(0.0, 1.0, estimate(0.0, 1.0), tolerance, 0)];
+		while let Some((a, b, whole, tolerance, depth)) = stack.pop() {
+			let m = (a + b) / 2.0;
+			let (left, right) = (estimate(a, m), estimate(m, b));
+			if (left + right - whole).abs() <= tolerance || depth == 32 {
+				length += left + right;
+			} else {
+				stack.push((a, m, left, tolerance / 2.0, depth + 1));
+				stack.push((m, b, right, tolerance / 2.0, depth + 1));
+			}
+		}
+		length
+	}
+
+	// disjoint parameter intervals in [0, 1] in ascending order isolating the intersections of the
+	// curve with the hyperplane normal · x = offset, which are the roots of the Bernstein polynomial
+	// with the coefficients normal · Pₖ - offset, see BernsteinPolynomial::root_intervals
+	//
+	// an uncertified interval is a tangential intersection or a near miss, which cannot be told apart
+	// at the given tolerance; a curve lying entirely in the hyperplane has no isolated intersections
+	// and returns an empty Vec
+	pub fn intersect_hyperplane_intervals(
+		&self,
+		normal: &[f64; D],
+		offset: f64,
+		tolerance: f64,
+	) -> Vec<RootInterval> {
+		let coeffs: SmallVec<[f64; 8]> = self
+			.control_points()
+			.iter()
+			.map(|p| p.iter().zip(normal.iter()).map(|(x, n)| x * n).sum::<f64>() - offset)
+			.collect();
+		BernsteinPolynomial::new(coeffs, 0.0, 1.0).root_intervals(tolerance)
+	}
+
+	// the midpoints of the intervals returned by intersect_hyperplane_intervals, including the
+	// uncertified ones, and thus also empty for a curve lying in the hyperplane
+	pub fn intersect_hyperplane(&self, normal: &[f64; D], offset: f64, tolerance: f64) -> Vec<f64> {
+		self.intersect_hyperplane_intervals(normal, offset, tolerance)
+			.iter()
+			.map(|interval| interval.lo + (interval.hi - interval.lo) / 2.0)
+			.collect()
+	}
+}
+
+impl BezierCurve<f64, 2> {
+	// the parameter intervals isolating the intersections with the line through point with the given
+	// direction, see intersect_hyperplane_intervals, which are empty if the curve lies on the line
+	pub fn intersect_line_intervals(
+		&self,
+		point: &[f64; 2],
+		direction: &[f64; 2],
+		tolerance: f64,
+	) -> Vec<RootInterval> {
+		let normal = [-direction[1], direction[0]];
+		let offset = normal[0] * point[0] + normal[1] * point[1];
+		self.intersect_hyperplane_intervals(&normal, offset, tolerance)
+	}
+
+	// the parameters t in [0, 1] at which the curve intersects the line, in ascending order, see
+	// intersect_hyperplane
+	pub fn intersect_line(
+		&self,
+		point: &[f64; 2],
+		direction: &[f64; 2],
+		tolerance: f64,
+	) -> Vec<f64> {
+		let normal = [-direction[1], direction[0]];
+		let offset = normal[0] * point[0] + normal[1] * point[1];
+		self.intersect_hyperplane(&normal, offset, tolerance)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use num::rational::Rational64;
+
+	fn r(n: i64, d: i64) -> Rational64 {
+		Rational64::new(n, d)
+	}
+
+	#[test]
+	fn test_eval_split() {
+		let points = [
+			[r(0, 1), r(0, 1), r(1, 1)],
+			[r(1, 1), r(2, 1), r(0, 1)],
+			[r(3, 1), r(-1, 1), r(2, 1)],
+			[r(4, 1), r(1, 1), r(1, 1)],
+		];
+		let curve = BezierCurve::new(&points);
+		assert_eq!(curve.degree(), 3);
+		assert_eq!(curve.control_points(), points.to_vec());
+		assert_eq!(curve.eval(&r(0, 1)), points[0]);
+		assert_eq!(curve.eval(&r(1, 1)), points[3]);
+
+		let t = r(1, 3);
+		let (left, right) = curve.split(&t);
+		assert_eq!(left.control_points()[3], curve.eval(&t));
+		assert_eq!(right.control_points()[0], curve.eval(&t));
+		for k in 0..=4 {
+			let s = r(k, 4);
+			assert_eq!(left.eval(&s), curve.eval(&(s * t)));
+			assert_eq!(right.eval(&s), curve.eval(&(t + s * (r(1, 1) - t))));
+		}
+
+		let polynomials = curve.to_polynomials();
+		for k in 0..=4 {
+			let s = r(k, 4);
+			let p = curve.eval(&s);
+			for axis in 0..3 {
+				assert_eq!(polynomials[axis].eval(s), p[axis]);
+			}
+		}
+	}
+
+	#[test]
+	fn test_derivative_bounding_box() {
+		let curve = BezierCurve::new(&[[0.0, 0.0], [1.0, 2.0], [2.0, 0.0]]);
+		let hodograph = curve.derivative();
+		assert_eq!(hodograph.control_points(), vec![[2.0, 4.0], [2.0, -4.0]]);
+		assert_eq!(hodograph.eval(&0.5), [2.0, 0.0]);
+
+		assert_eq!(curve.control_box(), ([0.0, 0.0], [2.0, 2.0]));
+		let (lo, hi) = curve.bounding_box();
+		assert_eq!(lo, [0.0, 0.0]);
+		assert!((hi[0] - 2.0).abs() < 1e-15 && (hi[1] - 1.0).abs() < 1e-12);
+
+		// the extrema of a cubic S-curve lie in the interior
+		let curve = BezierCurve::new(&[[0.0, 0.0], [0.0, 3.0], [1.0, -3.0], [1.0, 0.0]]);
+		let (lo, hi) = curve.bounding_box();
+		let samples = (0..=1000).map(|k| curve.eval(&(f64::from(k) / 1000.0))[1]);
+		let (min, max) = samples.fold((0f64, 0f64), |(l, h), y| (l.min(y), h.max(y)));
+		assert!(lo[1] <= min && min - lo[1] < 1e-5);
+		assert!(hi[1] >= max && hi[1] - max < 1e-5);
+	}
+
+	#[test]
+	fn test_arc_length() {
+		// a straight segment with unevenly spaced control points
+		let curve = BezierCurve::new(&[[0.0, 0.0], [0.3, 0.4], [2.7, 3.6], [3.0, 4.0]]);
+		assert!((curve.arc_length(1e-12) - 5.0).abs() < 1e-10);
+
+		// the parabola y = x² for x in [0, 1]
+		let curve = BezierCurve::new(&[[0.0, 0.0], [0.5, 0.0], [1.0, 1.0]]);
+		let exact = (2.0 * 5f64.sqrt() + 2f64.asinh()) / 4.0;
+		assert!((curve.arc_length(1e-12) - exact).abs() < 1e-10);
+	}
+
+	#[test]
+	fn test_intersect_line() {
+		let curve = BezierCurve::new(&[[0.0, -1.0], [1.0, 2.0], [2.0, -2.0], [3.0, 1.0]]);
+		let ts = curve.intersect_line(&[0.0, 0.0], &[1.0, 0.0], 1e-14);
+		assert_eq!(ts.len(), 3);
+		for t in ts.iter() {
+			assert!(curve.eval(t)[1].abs() < 1e-12);
+		}
+		assert!(ts.windows(2).all(|w| w[0] < w[1]));
+
+		// the vertical line x = 1.5 is crossed once
+		let ts = curve.intersect_line(&[1.5, 7.0], &[0.0, -2.0], 1e-14);
+		assert_eq!(ts.len(), 1);
+		assert!((curve.eval(&ts[0])[0] - 1.5).abs() < 1e-12);
+
+		// no intersection with a line above the control polygon
+		let ts = curve.intersect_line(&[0.0, 5.0], &[1.0, 0.1], 1e-14);
+		assert!(ts.is_empty());
+
+		let intervals = curve.intersect_line_intervals(&[0.0, 0.0], &[1.0, 0.0], 1e-14);
+		assert!(intervals.len() == 3 && intervals.iter().all(|interval| interval.certified));
+
+		// the tangent y = 1 at the apex of a parabola, and a line just below the vertex of another one
+		// at t = 0.3, which is not certified to intersect it at a coarse tolerance
+		let curve = BezierCurve::new(&[[0.0, 0.0], [1.0, 2.0], [2.0, 0.0]]);
+		let ts = curve.intersect_line(&[0.0, 1.0], &[1.0, 0.0], 1e-10);
+		assert_eq!(ts.len(), 1);
+		assert!((ts[0] - 0.5).abs() < 1e-5);
+		let curve = BezierCurve::new(&[[0.0, 0.09], [1.0, -0.21], [2.0, 0.49]]);
+		let intervals = curve.intersect_line_intervals(&[0.0, -1e-10], &[1.0, 0.0], 1e-4);
+		assert_eq!(intervals.len(), 1);
+		assert!(!intervals[0].certified && intervals[0].lo <= 0.3 && 0.3 <= intervals[0].hi);
+
+		// a curve lying on the line has no isolated intersections
+		let curve = BezierCurve::new(&[[0.0, 1.0], [1.0, 1.0], [3.0, 1.0]]);
+		assert!(curve
+			.intersect_line_intervals(&[0.0, 1.0], &[1.0, 0.0], 1e-10)
+			.is_empty());
+		assert!(curve
+			.intersect_line(&[0.0, 1.0], &[1.0, 0.0], 1e-10)
+			.is_empty());
+
+		// hyperplanes in three dimensions
+		let curve = BezierCurve::new(&[[0.0, 0.0, 0.0], [1.0, 1.0, 1.0], [2.0, 0.0, 2.0]]);
+		let ts = curve.intersect_hyperplane(&[0.0, 1.0, 0.0], 0.25, 1e-14);
+		assert_eq!(ts.len(), 2);
+		for t in ts.iter() {
+			assert!((curve.eval(t)[1] - 0.25).abs() < 1e-12);
+		}
+	}
+}
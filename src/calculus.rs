@@ -0,0 +1,317 @@
+use crate::Polynomial;
+
+use core::ops::{AddAssign, Div, Mul, MulAssign, Sub};
+use num::{FromPrimitive, One, Zero};
+use smallvec::SmallVec;
+
+// the falling factorial e(e - 1)⋯(e - n + 1) computed in T, or None if it is not representable
+// to rule out overflow before each multiplication, T must represent the exact partial product as long
+// as it fits into u128, and 2¹²⁸ beyond that, which excludes all fixed-width integer types
+pub(crate) fn falling_factorial<T>(e: u64, n: u64) -> Option<T>
+where
+	T: FromPrimitive,
+	for<'l> &'l T: Mul<T, Output = T>,
+{
+	let mut factorial = T::from_u64(1)?;
+	let mut exact = Some(1u128);
+	for k in (e + 1 - n)..=e {
+		exact = exact.and_then(|x| x.checked_mul(u128::from(k)));
+		match exact {
+			Some(x) => T::from_u128(x)?,
+			None => T::from_f64(2f64.powi(128))?,
+		};
+		factorial = &factorial * T::from_u64(k)?;
+	}
+	Some(factorial)
+}
+
+// integer types are detected by their division truncating 1 / 2 to zero
+fn truncating_division<T>() -> Option<bool>
+where
+	T: Zero + FromPrimitive,
+	for<'l> &'l T: Div<T, Output = T>,
+{
+	let two = T::from_u64(2)?;
+	Some(!two.is_zero() && (&T::from_u64(1)? / two).is_zero())
+}
+
+// the factorial scaling factors may not be representable in small integer types, and divisions may not
+// be exact for integer types or be divisions by zero in finite fields, in which case None is returned
+impl<T> Polynomial<T> {
+	pub fn derivative(&self, n: i32) -> Option<Self>
+	where
+		T: Zero + FromPrimitive,
+		for<'l> &'l T: Mul<T, Output = T>,
+	{
+		assert!(n >= 0);
+		let n = n as u64;
+		let mut rev_coeffs = SmallVec::new();
+		for (a, e_old) in self.rev_coeffs.iter().zip(0u64..).skip(n as usize) {
+			rev_coeffs.push(a * falling_factorial::<T>(e_old, n)?);
+		}
+		Some(Self::new_reversed(rev_coeffs))
+	}
+
+	pub fn antiderivative(&self, constant: T) -> Option<Self>
+	where
+		T: Zero + FromPrimitive + PartialEq,
+		for<'l> &'l T: Div<T, Output = T> + Mul<T, Output = T>,
+	{
+		let truncating = truncating_division::<T>()?;
+		let mut rev_coeffs = SmallVec::with_capacity(self.rev_coeffs.len() + 1);
+		rev_coeffs.push(constant);
+		for (a, e) in self.rev_coeffs.iter().zip(1u64..) {
+			// e is zero in fields whose characteristic divides it
+			if T::from_u64(e)?.is_zero() {
+				return None;
+			}
+			let quotient = a / T::from_u64(e)?;
+			if truncating && &quotient * T::from_u64(e)? != *a {
+				return None;
+			}
+			rev_coeffs.push(quotient);
+		}
+		Some(Self::new_reversed(rev_coeffs))
+	}
+
+	pub fn definite_integral(&self, a: T, b: T) -> Option<T>
+	where
+		T: Zero
+			+ One
+			+ FromPrimitive
+			+ PartialEq
+			+ AddAssign
+			+ Sub<Output = T>
+			+ for<'r> MulAssign<&'r T>,
+		for<'l, 'r> &'l T: Div<T, Output = T> + Mul<T, Output = T> + Mul<&'r T, Output = T>,
+	{
+		let antiderivative = self.antiderivative(T::zero())?;
+		Some(antiderivative.eval(b) - antiderivative.eval(a))
+	}
+
+	// p(x), p'(x), …, p⁽ᵏ⁾(x) via the extended Horner scheme
+	pub fn eval_all_derivatives<X, Y>(&self, x: X, k: usize) -> Option<Vec<Y>>
+	where
+		T: Zero + FromPrimitive,
+		for<'l, 'r> &'l T: Mul<&'r X, Output = Y> + Mul<T, Output = T>,
+		X: One,
+		Y: Zero + Clone + AddAssign + for<'r> MulAssign<&'r X> + MulAssign<T>,
+	{
+		let one = X::one();
+		let mut taylor = vec![Y::zero(); k + 1];
+		for (e, a) in self.rev_coeffs.iter().enumerate().rev() {
+			// coefficients of orders higher than e are still zero
+			for j in (1..=k.min(self.rev_coeffs.len() - 1 - e)).rev() {
+				taylor[j] *= &x;
+				let previous = taylor[j - 1].clone();
+				taylor[j] += previous;
+			}
+			taylor[0] *= &x;
+			taylor[0] += a * &one;
+		}
+
+		// the Taylor coefficients are p⁽ʲ⁾(x) / j!, where those above the order remain zero
+		let len = self.rev_coeffs.len();
+		for (j, y) in taylor.iter_mut().enumerate().take(len).skip(2) {
+			*y *= falling_factorial::<T>(j as u64, j as u64)?;
+		}
+		Some(taylor)
+	}
+
+	// re-expresses the polynomial in powers of (x - a), i.e., returns q with q(x) = p(x + a)
+	pub fn taylor_expand(&self, a: &T) -> Self
+	where
+		T: Zero + Clone + AddAssign,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T>,
+	{
+		let mut rev_coeffs = self.rev_coeffs.clone();
+		let n = rev_coeffs.len() - 1;
+		for i in 0..n {
+			for j in (i..n).rev() {
+				let v = a * &rev_coeffs[j + 1];
+				rev_coeffs[j] += v;
+			}
+		}
+		Self::new_reversed(rev_coeffs)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use num::rational::Rational64;
+
+	#[test]
+	fn test_derivative() {
+		// x³ + 2x² + 3x + 0
+		let poly = Polynomial::new(coefficients![1f32, 2.0, 3.0, 0.0]);
+		assert_eq!(poly.derivative(0).unwrap(), poly);
+		assert_eq!(
+			poly.derivative(1).unwrap().coeffs(),
+			coefficients![3f32, 4.0, 3.0]
+		);
+		assert_eq!(
+			poly.derivative(2).unwrap().coeffs(),
+			coefficients![6f32, 4.0]
+		);
+		assert_eq!(poly.derivative(3).unwrap().coeffs(), coefficients![6f32]);
+		assert!(poly.derivative(4).unwrap().is_zero());
+		for x in [0f32, 1.0, 2.0].iter() {
+			for n in 1..4 {
+				assert_eq!(poly.derivative(n).unwrap().eval(*x), poly.eval_der(*x, n));
+			}
+		}
+	}
+
+	#[test]
+	fn test_derivative_overflow() {
+		// the factor 256 is not representable in u8
+		let mut coeffs = coefficients![0u8; 257];
+		coeffs[0] = 1;
+		let poly = Polynomial::new(coeffs);
+		assert!(poly.derivative(1).is_none());
+
+		// 20! fits into 64 bits, but 21! does not
+		let mut coeffs = coefficients![0u64; 22];
+		coeffs[1] = 1;
+		let poly = Polynomial::new(coeffs.clone());
+		assert_eq!(
+			poly.derivative(20).unwrap().coeffs(),
+			coefficients![2_432_902_008_176_640_000u64]
+		);
+		coeffs[0] = 1;
+		let poly = Polynomial::new(coeffs);
+		assert!(poly.derivative(20).is_none());
+
+		// wider and floating point types are not limited to 64 bits
+		let coeffs = poly.coeffs().iter().map(|&c| c as i128).collect();
+		assert_eq!(
+			Polynomial::new(coeffs).derivative(20).unwrap().coeffs(),
+			coefficients![51_090_942_171_709_440_000i128, 2_432_902_008_176_640_000]
+		);
+		let coeffs = poly.coeffs().iter().map(|&c| c as f64).collect();
+		assert_eq!(
+			Polynomial::new(coeffs).derivative(20).unwrap().coeffs(),
+			coefficients![51_090_942_171_709_440_000f64, 2_432_902_008_176_640_000.0]
+		);
+		let mut coeffs = coefficients![num::BigInt::zero(); 41];
+		coeffs[0] = num::BigInt::one();
+		let factorial: num::BigInt = (1..=40u32).map(num::BigInt::from).product();
+		assert_eq!(
+			Polynomial::new(coeffs).derivative(40).unwrap().coeffs(),
+			coefficients![factorial]
+		);
+
+		// modular types reduce the factorial instead of overflowing
+		type F = ModInt<101>;
+		let poly = Polynomial::new((1..=40u64).map(F::new).collect());
+		let expected: Vec<F> = (35..=39u64)
+			.map(|e| F::new(40 - e) * (e - 34..=e).map(F::new).fold(F::new(1), |p, k| p * k))
+			.rev()
+			.collect();
+		assert_eq!(poly.derivative(35).unwrap().coeffs().to_vec(), expected);
+	}
+
+	#[test]
+	fn test_eval_all_derivatives() {
+		// x³ + 2x² + 3x + 0
+		let poly = Polynomial::new(coefficients![1f32, 2.0, 3.0, 0.0]);
+		assert_eq!(
+			poly.eval_all_derivatives(1f32, 3).unwrap(),
+			vec![6f32, 10.0, 10.0, 6.0]
+		);
+		assert_eq!(
+			poly.eval_all_derivatives(2f32, 5).unwrap(),
+			vec![22f32, 23.0, 16.0, 6.0, 0.0, 0.0]
+		);
+		assert_eq!(poly.eval_all_derivatives(2f32, 0).unwrap(), vec![22f32]);
+		let x = num::Complex::new(0f32, 1.0);
+		let values = poly.eval_all_derivatives(x, 2).unwrap();
+		assert_eq!(
+			values,
+			vec![poly.eval(x), poly.eval_der(x, 1), poly.eval_der(x, 2)]
+		);
+
+		// the j-th derivative at zero is j!, which exceeds 64 bits for j > 20
+		let poly = Polynomial::new(coefficients![1f64; 30]);
+		let values = poly.eval_all_derivatives(0f64, 31).unwrap();
+		for (j, value) in values.iter().enumerate() {
+			let factorial: f64 = (1..=j).map(|k| k as f64).product();
+			assert_eq!(*value, if j < 30 { factorial } else { 0.0 });
+		}
+		let poly = Polynomial::new(coefficients![1u64; 30]);
+		assert_eq!(
+			poly.eval_all_derivatives(0u64, 20).unwrap()[20],
+			2_432_902_008_176_640_000u64
+		);
+		assert!(poly.eval_all_derivatives(0u64, 21).is_none());
+
+		// derivatives above the order vanish without factorial scaling
+		let poly = Polynomial::new(coefficients![1i64, 2, 3]);
+		let mut expected = vec![0i64; 26];
+		expected[..3].copy_from_slice(&[6, 4, 2]);
+		assert_eq!(poly.eval_all_derivatives(1i64, 25).unwrap(), expected);
+	}
+
+	#[test]
+	fn test_taylor_expand() {
+		// x³ + 2x² + 3x = (x - 1)³ + 5(x - 1)² + 10(x - 1) + 6
+		let poly = Polynomial::new(coefficients![1, 2, 3, 0]);
+		let shifted = poly.taylor_expand(&1);
+		assert_eq!(shifted.coeffs(), coefficients![1, 5, 10, 6]);
+		assert_eq!(shifted.taylor_expand(&-1), poly);
+		assert_eq!(poly.taylor_expand(&0), poly);
+		assert_eq!(
+			Polynomial::new(coefficients![7]).taylor_expand(&3).coeffs(),
+			coefficients![7]
+		);
+	}
+
+	#[test]
+	fn test_antiderivative() {
+		let r = |n, d| Rational64::new(n, d);
+		// 3x² + 4x + 3
+		let poly = Polynomial::new(coefficients![r(3, 1), r(4, 1), r(3, 1)]);
+		let integral = poly.antiderivative(r(5, 1)).unwrap();
+		assert_eq!(
+			integral.coeffs(),
+			coefficients![r(1, 1), r(2, 1), r(3, 1), r(5, 1)]
+		);
+		assert_eq!(integral.derivative(1).unwrap(), poly);
+
+		// x²
+		let poly = Polynomial::new(coefficients![r(1, 1), r(0, 1), r(0, 1)]);
+		assert_eq!(
+			poly.antiderivative(r(0, 1)).unwrap().coeffs(),
+			coefficients![r(1, 3), r(0, 1), r(0, 1), r(0, 1)]
+		);
+		assert_eq!(poly.definite_integral(r(0, 1), r(1, 1)), Some(r(1, 3)));
+		assert_eq!(poly.definite_integral(r(-1, 1), r(2, 1)), Some(r(3, 1)));
+	}
+
+	#[test]
+	fn test_antiderivative_overflow() {
+		// 3x² + 4x + 5 can be integrated exactly, but not x² + 4x + 5
+		let poly = Polynomial::new(coefficients![3u8, 4, 5]);
+		assert_eq!(
+			poly.antiderivative(7).unwrap().coeffs(),
+			coefficients![1u8, 2, 5, 7]
+		);
+		let poly = Polynomial::new(coefficients![1u8, 4, 5]);
+		assert!(poly.antiderivative(0).is_none());
+
+		// the divisor 256 is not representable in u8
+		let mut coeffs = coefficients![0u8; 256];
+		coeffs[0] = 1;
+		assert!(Polynomial::new(coeffs).antiderivative(0).is_none());
+
+		// 101 is zero in GF(101), so x¹⁰⁰ has no antiderivative
+		type F = ModInt<101>;
+		let poly = Polynomial::new((1..=100u64).map(F::new).collect());
+		let antiderivative = poly.antiderivative(F::new(7)).unwrap();
+		assert_eq!(antiderivative.derivative(1).unwrap(), poly);
+		let poly = Polynomial::new((1..=101u64).map(F::new).collect());
+		assert!(poly.antiderivative(F::new(7)).is_none());
+		assert!(poly.definite_integral(F::new(0), F::new(1)).is_none());
+	}
+}
@@ -0,0 +1,542 @@
+use crate::linalg::{hessenberg_eigenvalues, Matrix};
+use crate::Polynomial;
+
+use core::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+use num::{Complex, FromPrimitive, One, Zero};
+use smallvec::{smallvec, SmallVec};
+
+// polynomial c₀T₀(x) + c₁T₁(x) + … + cₙTₙ(x) in the basis of Chebyshev polynomials of the first kind,
+// which is well-conditioned on [-1, 1], unlike the monomial basis
+//
+// the coefficients are stored as a Polynomial, as addition, subtraction and normalization are
+// coefficient-wise and therefore the same in both bases
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChebyshevPolynomial<T> {
+	coeffs: Polynomial<T>,
+}
+
+fn two<T: One + Add<Output = T>>() -> T {
+	T::one() + T::one()
+}
+
+impl<T> ChebyshevPolynomial<T> {
+	// coefficients c₀, c₁, … of T₀, T₁, …
+	pub fn new(coeffs: SmallVec<[T; 8]>) -> Self
+	where
+		T: Zero,
+	{
+		Self {
+			coeffs: Polynomial::new_reversed(coeffs),
+		}
+	}
+
+	pub fn coeffs(&self) -> &SmallVec<[T; 8]> {
+		&self.coeffs.rev_coeffs
+	}
+
+	pub fn into_coeffs(self) -> SmallVec<[T; 8]> {
+		self.coeffs.rev_coeffs
+	}
+
+	pub fn order(&self) -> i32 {
+		self.coeffs.order()
+	}
+
+	// Clenshaw's algorithm: bₖ = cₖ + 2x·bₖ₊₁ - bₖ₊₂ and p(x) = c₀ + x·b₁ - b₂
+	pub fn eval(&self, x: &T) -> T
+	where
+		T: Zero + One + Clone + AddAssign + SubAssign,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T>,
+	{
+		let two_x = &two::<T>() * x;
+		let (mut b1, mut b2) = (T::zero(), T::zero());
+		for c in self.coeffs().iter().skip(1).rev() {
+			let mut b = &two_x * &b1;
+			b -= b2;
+			b += c.clone();
+			b2 = core::mem::replace(&mut b1, b);
+		}
+		let mut y = x * &b1;
+		y -= b2;
+		y += self.coeffs()[0].clone();
+		y
+	}
+
+	// d/dx, using the recurrence c'ₖ₋₁ = c'ₖ₊₁ + 2k·cₖ with c'₀ halved
+	pub fn derivative(&self) -> Self
+	where
+		T: Zero + One + Clone + AddAssign + FromPrimitive,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let n = self.coeffs().len();
+		if n == 1 {
+			return Self::new(smallvec![T::zero()]);
+		}
+		// c'ₙ₋₁ and c'ₙ are zero
+		let mut derivative: SmallVec<[T; 8]> = (0..n + 1).map(|_| T::zero()).collect();
+		for k in (1..n).rev() {
+			let mut v = derivative[k + 1].clone();
+			v += &T::from_usize(2 * k).unwrap() * &self.coeffs()[k];
+			derivative[k - 1] = v;
+		}
+		derivative[0] = &derivative[0] / &two();
+		derivative.truncate(n - 1);
+		Self::new(derivative)
+	}
+
+	// the antiderivative F with F(-1) = 0, using ∫Tₖ = Tₖ₊₁ / 2(k + 1) - Tₖ₋₁ / 2(k - 1) for k > 1,
+	// ∫T₀ = T₁ and ∫T₁ = T₂ / 4
+	pub fn antiderivative(&self) -> Self
+	where
+		T: Zero + One + Clone + AddAssign + SubAssign + FromPrimitive,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let coeffs = self.coeffs();
+		let n = coeffs.len();
+		let mut integral: SmallVec<[T; 8]> = (0..n + 1).map(|_| T::zero()).collect();
+		for (k, c) in coeffs.iter().enumerate() {
+			match k {
+				0 => integral[1] += c.clone(),
+				1 => integral[2] += c / &T::from_u8(4).unwrap(),
+				_ => {
+					integral[k + 1] += c / &T::from_usize(2 * (k + 1)).unwrap();
+					integral[k - 1] -= c / &T::from_usize(2 * (k - 1)).unwrap();
+				}
+			}
+		}
+
+		// Tₖ(-1) = (-1)ᵏ
+		let mut value = T::zero();
+		for (k, c) in integral.iter().enumerate().skip(1) {
+			if k % 2 == 0 {
+				value += c.clone();
+			} else {
+				value -= c.clone();
+			}
+		}
+		integral[0] -= value;
+		Self::new(integral)
+	}
+
+	// converts to the monomial basis using Tₖ₊₁ = 2x·Tₖ - Tₖ₋₁
+	pub fn to_polynomial(&self) -> Polynomial<T>
+	where
+		T: Zero + One + Clone + AddAssign + SubAssign,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T>,
+	{
+		let two = two::<T>();
+		let mut rev_coeffs: SmallVec<[T; 8]> = smallvec![self.coeffs()[0].clone()];
+		let mut t_prev: SmallVec<[T; 8]> = smallvec![T::one()];
+		let mut t_curr: SmallVec<[T; 8]> = smallvec![T::zero(), T::one()];
+		for c in self.coeffs().iter().skip(1) {
+			rev_coeffs.push(T::zero());
+			for (r, t) in rev_coeffs.iter_mut().zip(t_curr.iter()) {
+				*r += c * t;
+			}
+			let mut t_next: SmallVec<[T; 8]> = core::iter::once(T::zero())
+				.chain(t_curr.iter().map(|t| &two * t))
+				.collect();
+			for (n, p) in t_next.iter_mut().zip(t_prev.iter()) {
+				*n -= p.clone();
+			}
+			t_prev = core::mem::replace(&mut t_curr, t_next);
+		}
+		Polynomial::new_reversed(rev_coeffs)
+	}
+}
+
+impl<T> Polynomial<T> {
+	// converts to the Chebyshev basis using Horner's scheme with x·T₀ = T₁ and
+	// x·Tₖ = (Tₖ₊₁ + Tₖ₋₁) / 2, which is exact for exact types
+	pub fn to_chebyshev(&self) -> ChebyshevPolynomial<T>
+	where
+		T: Zero + One + Clone + AddAssign,
+		for<'l, 'r> &'l T: Div<&'r T, Output = T>,
+	{
+		let two = two::<T>();
+		let mut coeffs: SmallVec<[T; 8]> = SmallVec::new();
+		for a in self.rev_coeffs.iter().rev() {
+			let mut shifted: SmallVec<[T; 8]> = (0..coeffs.len() + 1).map(|_| T::zero()).collect();
+			for (k, c) in coeffs.iter().enumerate() {
+				if k == 0 {
+					shifted[1] += c.clone();
+				} else {
+					let half = c / &two;
+					shifted[k + 1] += half.clone();
+					shifted[k - 1] += half;
+				}
+			}
+			shifted[0] += a.clone();
+			coeffs = shifted;
+		}
+		ChebyshevPolynomial::new(coeffs)
+	}
+}
+
+// the adaptive construction stops at this number of Chebyshev points
+const MAX_POINTS_LOG2: u32 = 16;
+
+// in-place iterative radix-2 fast Fourier transform, the length must be a power of two
+fn fft(values: &mut [Complex<f64>]) {
+	let n = values.len();
+	assert!(n.is_power_of_two());
+	let bits = n.trailing_zeros();
+	for i in 0..n {
+		let j = i.reverse_bits() >> (usize::BITS - bits) as usize;
+		if i < j {
+			values.swap(i, j);
+		}
+	}
+	let mut len = 2;
+	while len <= n {
+		let angle = -2.0 * std::f64::consts::PI / len as f64;
+		for start in (0..n).step_by(len) {
+			for k in 0..len / 2 {
+				let w = Complex::from_polar(&1.0, &(angle * k as f64));
+				let a = values[start + k];
+				let b = values[start + k + len / 2] * w;
+				values[start + k] = a + b;
+				values[start + k + len / 2] = a - b;
+			}
+		}
+		len *= 2;
+	}
+}
+
+impl ChebyshevPolynomial<f64> {
+	// the interpolant through the values at the n + 1 Chebyshev points xⱼ = cos(πj / n) of the
+	// second kind, where n must be a power of two, computed by a type-I discrete cosine transform
+	// via an FFT of the even extension of length 2n
+	pub fn interpolate_chebyshev_points(values: &[f64]) -> Self {
+		let n = values.len() - 1;
+		assert!(n.is_power_of_two());
+		let mut extended: Vec<Complex<f64>> = values
+			.iter()
+			.chain(values[1..n].iter().rev())
+			.map(|&v| Complex::new(v, 0.0))
+			.collect();
+		fft(&mut extended);
+		let mut coeffs: SmallVec<[f64; 8]> =
+			extended[..=n].iter().map(|c| c.re / n as f64).collect();
+		coeffs[0] /= 2.0;
+		coeffs[n] /= 2.0;
+		Self::new(coeffs)
+	}
+
+	// chebfun-like adaptive approximation of f on [-1, 1]: f is interpolated at 17, 33, 65, …
+	// Chebyshev points until the coefficients have decayed below tolerance relative to the largest
+	// one, and the series is truncated after the last coefficient above that level, or to a constant
+	// if there is none, which happens for tolerance ≥ 1
+	// returns None if f is not resolved by 2¹⁶ + 1 points, which happens e.g. for non-smooth f
+	pub fn approximate<F: Fn(f64) -> f64>(f: F, tolerance: f64) -> Option<Self> {
+		assert!(tolerance > 0.0);
+		for log2 in 4..=MAX_POINTS_LOG2 {
+			let n = 1usize << log2;
+			let values: Vec<f64> = (0..=n)
+				.map(|j| f((std::f64::consts::PI * j as f64 / n as f64).cos()))
+				.collect();
+			let interpolant = Self::interpolate_chebyshev_points(&values);
+			let coeffs = interpolant.coeffs();
+			let scale = coeffs.iter().fold(0f64, |s, c| s.max(c.abs()));
+			if scale == 0.0 {
+				return Some(interpolant);
+			}
+
+			// the coefficients have decayed if the tail of length n / 8 is negligible
+			let threshold = tolerance * scale;
+			if coeffs.len() <= n - n / 8 || coeffs[n - n / 8..].iter().all(|c| c.abs() <= threshold)
+			{
+				let len = coeffs
+					.iter()
+					.rposition(|c| c.abs() > threshold)
+					.map_or(1, |k| k + 1);
+				return Some(Self::new(coeffs[..len].iter().cloned().collect()));
+			}
+		}
+		None
+	}
+
+	// the real roots in [-1, 1] as eigenvalues of the colleague matrix, sorted in ascending order,
+	// see Good: "The colleague matrix, a Chebyshev analogue of the companion matrix" (1961)
+	// returns None if the eigenvalue iteration does not converge
+	pub fn roots(&self) -> Option<Vec<f64>> {
+		let coeffs = self.coeffs();
+		let n = coeffs.len() - 1;
+		if n == 0 {
+			return Some(Vec::new());
+		}
+
+		// the transpose of the colleague matrix is upper Hessenberg
+		let mut colleague = Matrix::zeros(n, n);
+		for i in 0..n - 1 {
+			*colleague.get_mut(i + 1, i) = if i == 0 { 1.0 } else { 0.5 };
+			*colleague.get_mut(i, i + 1) = 0.5;
+		}
+		for (i, c) in coeffs.iter().take(n).enumerate() {
+			*colleague.get_mut(i, n - 1) -= c / (2.0 * coeffs[n]);
+		}
+
+		// eigenvalues slightly outside [-1, 1] or off the real axis are attributed to rounding
+		let margin = 1e-8;
+		let derivative = self.derivative();
+		let mut roots: Vec<f64> = hessenberg_eigenvalues(&colleague)?
+			.into_iter()
+			.filter(|z| z.im.abs() <= margin && z.re.abs() <= 1.0 + margin)
+			.map(|z| {
+				// polishing by one Newton step
+				let x = z.re.clamp(-1.0, 1.0);
+				let d = derivative.eval(&x);
+				let x = if d != 0.0 { x - self.eval(&x) / d } else { x };
+				x.clamp(-1.0, 1.0)
+			})
+			.collect();
+		roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+		Some(roots)
+	}
+}
+
+impl<T> Add<&ChebyshevPolynomial<T>> for &ChebyshevPolynomial<T>
+where
+	T: for<'r> AddAssign<&'r T> + Zero + Clone,
+{
+	type Output = ChebyshevPolynomial<T>;
+
+	fn add(self, rhs: &ChebyshevPolynomial<T>) -> ChebyshevPolynomial<T> {
+		ChebyshevPolynomial {
+			coeffs: &self.coeffs + &rhs.coeffs,
+		}
+	}
+}
+
+impl<T> Add<ChebyshevPolynomial<T>> for ChebyshevPolynomial<T>
+where
+	T: for<'r> AddAssign<&'r T> + Zero,
+{
+	type Output = ChebyshevPolynomial<T>;
+
+	fn add(self, rhs: ChebyshevPolynomial<T>) -> ChebyshevPolynomial<T> {
+		ChebyshevPolynomial {
+			coeffs: self.coeffs + rhs.coeffs,
+		}
+	}
+}
+
+impl<T> Sub<&ChebyshevPolynomial<T>> for &ChebyshevPolynomial<T>
+where
+	T: for<'r> SubAssign<&'r T> + Zero + Clone,
+{
+	type Output = ChebyshevPolynomial<T>;
+
+	fn sub(self, rhs: &ChebyshevPolynomial<T>) -> ChebyshevPolynomial<T> {
+		ChebyshevPolynomial {
+			coeffs: &self.coeffs - &rhs.coeffs,
+		}
+	}
+}
+
+impl<T> Sub<ChebyshevPolynomial<T>> for ChebyshevPolynomial<T>
+where
+	T: for<'r> SubAssign<&'r T> + Zero,
+{
+	type Output = ChebyshevPolynomial<T>;
+
+	fn sub(self, rhs: ChebyshevPolynomial<T>) -> ChebyshevPolynomial<T> {
+		ChebyshevPolynomial {
+			coeffs: self.coeffs - rhs.coeffs,
+		}
+	}
+}
+
+// Tₘ · Tₙ = (Tₘ₊ₙ + T|ₘ₋ₙ|) / 2
+impl<T> Mul<&ChebyshevPolynomial<T>> for &ChebyshevPolynomial<T>
+where
+	T: Zero + One + AddAssign,
+	for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+{
+	type Output = ChebyshevPolynomial<T>;
+
+	fn mul(self, rhs: &ChebyshevPolynomial<T>) -> ChebyshevPolynomial<T> {
+		let (lhs, rhs) = (self.coeffs(), rhs.coeffs());
+		let mut coeffs: SmallVec<[T; 8]> =
+			(0..lhs.len() + rhs.len() - 1).map(|_| T::zero()).collect();
+		for (m, a) in lhs.iter().enumerate() {
+			for (n, b) in rhs.iter().enumerate() {
+				coeffs[m + n] += a * b;
+				coeffs[m.abs_diff(n)] += a * b;
+			}
+		}
+		let two = two::<T>();
+		ChebyshevPolynomial::new(coeffs.iter().map(|c| c / &two).collect())
+	}
+}
+
+impl<T> Mul<ChebyshevPolynomial<T>> for ChebyshevPolynomial<T>
+where
+	T: Zero + One + AddAssign,
+	for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+{
+	type Output = ChebyshevPolynomial<T>;
+
+	fn mul(self, rhs: ChebyshevPolynomial<T>) -> ChebyshevPolynomial<T> {
+		&self * &rhs
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use num::rational::Rational64;
+
+	fn r(n: i64, d: i64) -> Rational64 {
+		Rational64::new(n, d)
+	}
+
+	#[test]
+	fn test_conversion() {
+		// T₃ = 4x³ - 3x
+		let t3 = ChebyshevPolynomial::new(coefficients![r(0, 1), r(0, 1), r(0, 1), r(1, 1)]);
+		assert_eq!(
+			t3.to_polynomial().coeffs(),
+			coefficients![r(4, 1), r(0, 1), r(-3, 1), r(0, 1)]
+		);
+
+		// x² = (T₀ + T₂) / 2
+		let poly = Polynomial::new(coefficients![r(1, 1), r(0, 1), r(0, 1)]);
+		assert_eq!(
+			poly.to_chebyshev().coeffs(),
+			&coefficients![r(1, 2), r(0, 1), r(1, 2)]
+		);
+
+		let poly = Polynomial::new((1..=11).map(|k| r(k * k - 30, k)).collect());
+		assert_eq!(poly.to_chebyshev().to_polynomial(), poly);
+		let zero = Polynomial::new(coefficients![r(0, 1)]);
+		assert_eq!(zero.to_chebyshev().coeffs(), &coefficients![r(0, 1)]);
+	}
+
+	#[test]
+	fn test_eval() {
+		let poly = Polynomial::new((1..=9).map(|k| r(5 - k, k)).collect());
+		let chebyshev = poly.to_chebyshev();
+		for k in -4..=4 {
+			let x = r(k, 3);
+			assert_eq!(chebyshev.eval(&x), poly.eval(x));
+		}
+
+		// Tₙ(cos θ) = cos(nθ)
+		let t7 = ChebyshevPolynomial::new(coefficients![0f64, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+		for &theta in [0.1f64, 1.0, 2.5].iter() {
+			assert!((t7.eval(&theta.cos()) - (7.0 * theta).cos()).abs() < 1e-14);
+		}
+	}
+
+	#[test]
+	fn test_arithmetic() {
+		let a = Polynomial::new((1..=6).map(|k| r(k, 2)).collect());
+		let b = Polynomial::new((1..=4).map(|k| r(3, k)).collect());
+		let (ca, cb) = (a.to_chebyshev(), b.to_chebyshev());
+		assert_eq!((&ca + &cb).to_polynomial(), &a + &b);
+		assert_eq!((&ca - &cb).to_polynomial(), &a - &b);
+		assert_eq!((&ca * &cb).to_polynomial(), &a * &b);
+		assert_eq!((ca.clone() - ca.clone()).order(), 0);
+		assert_eq!(
+			(ca.clone() + cb.clone()) * cb.clone(),
+			(&ca * &cb) + (&cb * &cb)
+		);
+	}
+
+	#[test]
+	fn test_calculus() {
+		let poly = Polynomial::new((1..=8).map(|k| r(k - 4, k)).collect());
+		let chebyshev = poly.to_chebyshev();
+		assert_eq!(
+			chebyshev.derivative().to_polynomial(),
+			poly.derivative(1).unwrap()
+		);
+		let integral = chebyshev.antiderivative();
+		assert_eq!(integral.derivative(), chebyshev);
+		assert_eq!(integral.eval(&r(-1, 1)), r(0, 1));
+		assert_eq!(
+			integral.eval(&r(1, 1)),
+			poly.definite_integral(r(-1, 1), r(1, 1)).unwrap()
+		);
+		let constant = ChebyshevPolynomial::new(coefficients![r(3, 1)]);
+		assert_eq!(constant.derivative().coeffs(), &coefficients![r(0, 1)]);
+	}
+
+	#[test]
+	fn test_interpolate_chebyshev_points() {
+		// x² = (T₀ + T₂) / 2
+		let values: Vec<f64> = (0..=4)
+			.map(|j| (std::f64::consts::PI * f64::from(j) / 4.0).cos().powi(2))
+			.collect();
+		let interpolant = ChebyshevPolynomial::interpolate_chebyshev_points(&values);
+		let expected = [0.5, 0.0, 0.5];
+		assert!(interpolant.order() <= 4);
+		for (c, e) in interpolant
+			.coeffs()
+			.iter()
+			.zip(expected.iter().chain([0.0; 2].iter()))
+		{
+			assert!((c - e).abs() < 1e-15);
+		}
+	}
+
+	#[test]
+	fn test_approximate() {
+		let approximation = ChebyshevPolynomial::approximate(f64::exp, 1e-15).unwrap();
+		assert!(approximation.order() < 20);
+		for k in 0..=100 {
+			let x = f64::from(k) / 50.0 - 1.0;
+			assert!((approximation.eval(&x) - x.exp()).abs() < 1e-14);
+		}
+
+		let runge = |x: f64| 1.0 / (1.0 + 25.0 * x * x);
+		let approximation = ChebyshevPolynomial::approximate(runge, 1e-10).unwrap();
+		assert!(approximation.order() < 200);
+		assert!((approximation.eval(&0.3) - runge(0.3)).abs() < 1e-9);
+
+		let zero = ChebyshevPolynomial::approximate(|_| 0.0, 1e-10).unwrap();
+		assert_eq!(zero.order(), 0);
+		let zero = ChebyshevPolynomial::approximate(|_| 0.0, 2.0).unwrap();
+		assert_eq!(zero.order(), 0);
+
+		// no coefficient exceeds a tolerance of at least one, leaving the mean value I₀(1) of exp
+		for &tolerance in [1.0, 2.0].iter() {
+			let constant = ChebyshevPolynomial::approximate(f64::exp, tolerance).unwrap();
+			assert_eq!(constant.order(), 0);
+			assert!((constant.eval(&0.0) - 1.266_065_877_752_008_4).abs() < 1e-14);
+		}
+
+		// the sign function is not resolved
+		assert!(ChebyshevPolynomial::approximate(|x: f64| x.signum(), 1e-10).is_none());
+	}
+
+	#[test]
+	fn test_roots() {
+		// Tₙ has the roots cos((2k - 1)π / 2n)
+		let t5 = ChebyshevPolynomial::new(coefficients![0f64, 0.0, 0.0, 0.0, 0.0, 1.0]);
+		let roots = t5.roots().unwrap();
+		assert_eq!(roots.len(), 5);
+		for (k, root) in roots.iter().rev().enumerate() {
+			let expected = (std::f64::consts::PI * (2 * k + 1) as f64 / 10.0).cos();
+			assert!((root - expected).abs() < 1e-14);
+		}
+
+		// sin(3πx) has the roots k / 3 for k from -3 to 3
+		let f = |x: f64| (3.0 * std::f64::consts::PI * x).sin();
+		let roots = ChebyshevPolynomial::approximate(f, 1e-14)
+			.unwrap()
+			.roots()
+			.unwrap();
+		assert_eq!(roots.len(), 7);
+		for (k, root) in roots.iter().enumerate() {
+			assert!((root - (k as f64 - 3.0) / 3.0).abs() < 1e-12);
+		}
+
+		// x² + 1 has no real roots
+		let poly = Polynomial::new(coefficients![1f64, 0.0, 1.0]).to_chebyshev();
+		assert!(poly.roots().unwrap().is_empty());
+		let constant = ChebyshevPolynomial::new(coefficients![2f64]);
+		assert!(constant.roots().unwrap().is_empty());
+	}
+}
@@ -0,0 +1,645 @@
+use crate::{ModInt, Polynomial};
+
+use num::{One, Zero};
+use smallvec::SmallVec;
+
+// CRC parameters according to the Rocksoft model, as catalogued at https://reveng.sourceforge.io/crc-catalogue/
+// poly, init and xor_out are given without reflection, check is the CRC of the ASCII string "123456789"
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CrcParams {
+	pub name: &'static str,
+	pub width: u32,
+	pub poly: u64,
+	pub init: u64,
+	pub reflect_in: bool,
+	pub reflect_out: bool,
+	pub xor_out: u64,
+	pub check: u64,
+}
+
+pub const CRC_3_GSM: CrcParams = CrcParams {
+	name: "CRC-3/GSM",
+	width: 3,
+	poly: 0x3,
+	init: 0x0,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: 0x7,
+	check: 0x4,
+};
+
+pub const CRC_5_USB: CrcParams = CrcParams {
+	name: "CRC-5/USB",
+	width: 5,
+	poly: 0x05,
+	init: 0x1f,
+	reflect_in: true,
+	reflect_out: true,
+	xor_out: 0x1f,
+	check: 0x19,
+};
+
+pub const CRC_8_AUTOSAR: CrcParams = CrcParams {
+	name: "CRC-8/AUTOSAR",
+	width: 8,
+	poly: 0x2f,
+	init: 0xff,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: 0xff,
+	check: 0xdf,
+};
+
+pub const CRC_8_MAXIM_DOW: CrcParams = CrcParams {
+	name: "CRC-8/MAXIM-DOW",
+	width: 8,
+	poly: 0x31,
+	init: 0x00,
+	reflect_in: true,
+	reflect_out: true,
+	xor_out: 0x00,
+	check: 0xa1,
+};
+
+pub const CRC_8_SMBUS: CrcParams = CrcParams {
+	name: "CRC-8/SMBUS",
+	width: 8,
+	poly: 0x07,
+	init: 0x00,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: 0x00,
+	check: 0xf4,
+};
+
+pub const CRC_12_UMTS: CrcParams = CrcParams {
+	name: "CRC-12/UMTS",
+	width: 12,
+	poly: 0x80f,
+	init: 0x000,
+	reflect_in: false,
+	reflect_out: true,
+	xor_out: 0x000,
+	check: 0xdaf,
+};
+
+pub const CRC_15_CAN: CrcParams = CrcParams {
+	name: "CRC-15/CAN",
+	width: 15,
+	poly: 0x4599,
+	init: 0x0000,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: 0x0000,
+	check: 0x059e,
+};
+
+pub const CRC_16_ARC: CrcParams = CrcParams {
+	name: "CRC-16/ARC",
+	width: 16,
+	poly: 0x8005,
+	init: 0x0000,
+	reflect_in: true,
+	reflect_out: true,
+	xor_out: 0x0000,
+	check: 0xbb3d,
+};
+
+pub const CRC_16_GENIBUS: CrcParams = CrcParams {
+	name: "CRC-16/GENIBUS",
+	width: 16,
+	poly: 0x1021,
+	init: 0xffff,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: 0xffff,
+	check: 0xd64e,
+};
+
+pub const CRC_16_IBM_3740: CrcParams = CrcParams {
+	name: "CRC-16/IBM-3740",
+	width: 16,
+	poly: 0x1021,
+	init: 0xffff,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: 0x0000,
+	check: 0x29b1,
+};
+
+pub const CRC_16_IBM_SDLC: CrcParams = CrcParams {
+	name: "CRC-16/IBM-SDLC",
+	width: 16,
+	poly: 0x1021,
+	init: 0xffff,
+	reflect_in: true,
+	reflect_out: true,
+	xor_out: 0xffff,
+	check: 0x906e,
+};
+
+pub const CRC_16_KERMIT: CrcParams = CrcParams {
+	name: "CRC-16/KERMIT",
+	width: 16,
+	poly: 0x1021,
+	init: 0x0000,
+	reflect_in: true,
+	reflect_out: true,
+	xor_out: 0x0000,
+	check: 0x2189,
+};
+
+pub const CRC_16_MODBUS: CrcParams = CrcParams {
+	name: "CRC-16/MODBUS",
+	width: 16,
+	poly: 0x8005,
+	init: 0xffff,
+	reflect_in: true,
+	reflect_out: true,
+	xor_out: 0x0000,
+	check: 0x4b37,
+};
+
+pub const CRC_16_XMODEM: CrcParams = CrcParams {
+	name: "CRC-16/XMODEM",
+	width: 16,
+	poly: 0x1021,
+	init: 0x0000,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: 0x0000,
+	check: 0x31c3,
+};
+
+pub const CRC_24_OPENPGP: CrcParams = CrcParams {
+	name: "CRC-24/OPENPGP",
+	width: 24,
+	poly: 0x86_4cfb,
+	init: 0xb7_04ce,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: 0x00_0000,
+	check: 0x21_cf02,
+};
+
+pub const CRC_32_AUTOSAR: CrcParams = CrcParams {
+	name: "CRC-32/AUTOSAR",
+	width: 32,
+	poly: 0xf4ac_fb13,
+	init: 0xffff_ffff,
+	reflect_in: true,
+	reflect_out: true,
+	xor_out: 0xffff_ffff,
+	check: 0x1697_d06a,
+};
+
+pub const CRC_32_BZIP2: CrcParams = CrcParams {
+	name: "CRC-32/BZIP2",
+	width: 32,
+	poly: 0x04c1_1db7,
+	init: 0xffff_ffff,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: 0xffff_ffff,
+	check: 0xfc89_1918,
+};
+
+pub const CRC_32_CKSUM: CrcParams = CrcParams {
+	name: "CRC-32/CKSUM",
+	width: 32,
+	poly: 0x04c1_1db7,
+	init: 0x0000_0000,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: 0xffff_ffff,
+	check: 0x765e_7680,
+};
+
+pub const CRC_32_ISCSI: CrcParams = CrcParams {
+	name: "CRC-32/ISCSI",
+	width: 32,
+	poly: 0x1edc_6f41,
+	init: 0xffff_ffff,
+	reflect_in: true,
+	reflect_out: true,
+	xor_out: 0xffff_ffff,
+	check: 0xe306_9283,
+};
+
+pub const CRC_32_ISO_HDLC: CrcParams = CrcParams {
+	name: "CRC-32/ISO-HDLC",
+	width: 32,
+	poly: 0x04c1_1db7,
+	init: 0xffff_ffff,
+	reflect_in: true,
+	reflect_out: true,
+	xor_out: 0xffff_ffff,
+	check: 0xcbf4_3926,
+};
+
+pub const CRC_32_MPEG_2: CrcParams = CrcParams {
+	name: "CRC-32/MPEG-2",
+	width: 32,
+	poly: 0x04c1_1db7,
+	init: 0xffff_ffff,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: 0x0000_0000,
+	check: 0x0376_e6e7,
+};
+
+pub const CRC_64_ECMA_182: CrcParams = CrcParams {
+	name: "CRC-64/ECMA-182",
+	width: 64,
+	poly: 0x42f0_e1eb_a9ea_3693,
+	init: 0,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: 0,
+	check: 0x6c40_df5f_0b49_7347,
+};
+
+pub const CRC_64_GO_ISO: CrcParams = CrcParams {
+	name: "CRC-64/GO-ISO",
+	width: 64,
+	poly: 0x1b,
+	init: !0,
+	reflect_in: true,
+	reflect_out: true,
+	xor_out: !0,
+	check: 0xb909_56c7_75a4_1001,
+};
+
+pub const CRC_64_WE: CrcParams = CrcParams {
+	name: "CRC-64/WE",
+	width: 64,
+	poly: 0x42f0_e1eb_a9ea_3693,
+	init: !0,
+	reflect_in: false,
+	reflect_out: false,
+	xor_out: !0,
+	check: 0x62ec_59e3_f1a4_f00a,
+};
+
+pub const CRC_64_XZ: CrcParams = CrcParams {
+	name: "CRC-64/XZ",
+	width: 64,
+	poly: 0x42f0_e1eb_a9ea_3693,
+	init: !0,
+	reflect_in: true,
+	reflect_out: true,
+	xor_out: !0,
+	check: 0x995d_c9bb_df19_39fa,
+};
+
+pub const CRC_CATALOGUE: &[CrcParams] = &[
+	CRC_3_GSM,
+	CRC_5_USB,
+	CRC_8_AUTOSAR,
+	CRC_8_MAXIM_DOW,
+	CRC_8_SMBUS,
+	CRC_12_UMTS,
+	CRC_15_CAN,
+	CRC_16_ARC,
+	CRC_16_GENIBUS,
+	CRC_16_IBM_3740,
+	CRC_16_IBM_SDLC,
+	CRC_16_KERMIT,
+	CRC_16_MODBUS,
+	CRC_16_XMODEM,
+	CRC_24_OPENPGP,
+	CRC_32_AUTOSAR,
+	CRC_32_BZIP2,
+	CRC_32_CKSUM,
+	CRC_32_ISCSI,
+	CRC_32_ISO_HDLC,
+	CRC_32_MPEG_2,
+	CRC_64_ECMA_182,
+	CRC_64_GO_ISO,
+	CRC_64_WE,
+	CRC_64_XZ,
+];
+
+fn mask(width: u32) -> u64 {
+	u64::MAX >> (64 - width)
+}
+
+fn reflect(value: u64, width: u32) -> u64 {
+	value.reverse_bits() >> (64 - width)
+}
+
+impl CrcParams {
+	// G(x) = xʷ + poly(x)
+	pub fn generator(&self) -> Polynomial<ModInt<2>> {
+		let coefficients = core::iter::once(ModInt::one())
+			.chain(
+				(0..self.width)
+					.rev()
+					.map(|k| ModInt::new(self.poly >> k & 1)),
+			)
+			.collect();
+		Polynomial::new(coefficients)
+	}
+}
+
+// the shift register is kept in the low bits in reflected form for reflected input,
+// and in the high bits otherwise, so that both cases can be processed bytewise for any width
+#[derive(Clone)]
+pub struct Crc {
+	params: CrcParams,
+	tables: Box<[[u64; 256]; 8]>,
+}
+
+#[derive(Clone)]
+pub struct CrcDigest<'a> {
+	crc: &'a Crc,
+	register: u64,
+}
+
+impl Crc {
+	pub fn new(params: CrcParams) -> Self {
+		assert!(params.width >= 1 && params.width <= 64);
+		assert!(params.poly & !mask(params.width) == 0);
+
+		let mut tables = Box::new([[0u64; 256]; 8]);
+		if params.reflect_in {
+			let poly = reflect(params.poly, params.width);
+			for i in 0..256 {
+				let mut register = i as u64;
+				for _ in 0..8 {
+					register = if register & 1 == 1 {
+						(register >> 1) ^ poly
+					} else {
+						register >> 1
+					};
+				}
+				tables[0][i] = register;
+			}
+			for k in 1..8 {
+				for i in 0..256 {
+					let previous = tables[k - 1][i];
+					tables[k][i] = (previous >> 8) ^ tables[0][(previous & 0xff) as usize];
+				}
+			}
+		} else {
+			let poly = params.poly << (64 - params.width);
+			for i in 0..256 {
+				let mut register = (i as u64) << 56;
+				for _ in 0..8 {
+					register = if register >> 63 == 1 {
+						(register << 1) ^ poly
+					} else {
+						register << 1
+					};
+				}
+				tables[0][i] = register;
+			}
+			for k in 1..8 {
+				for i in 0..256 {
+					let previous = tables[k - 1][i];
+					tables[k][i] = (previous << 8) ^ tables[0][(previous >> 56) as usize];
+				}
+			}
+		}
+
+		Self { params, tables }
+	}
+
+	pub fn params(&self) -> &CrcParams {
+		&self.params
+	}
+
+	fn initial_register(&self) -> u64 {
+		if self.params.reflect_in {
+			reflect(self.params.init, self.params.width)
+		} else {
+			self.params.init << (64 - self.params.width)
+		}
+	}
+
+	fn finalize(&self, register: u64) -> u64 {
+		let width = self.params.width;
+		let register = if self.params.reflect_in {
+			reflect(register, width)
+		} else {
+			register >> (64 - width)
+		};
+		let register = if self.params.reflect_out {
+			reflect(register, width)
+		} else {
+			register
+		};
+		(register ^ self.params.xor_out) & mask(width)
+	}
+
+	fn update_bytewise(&self, register: u64, data: &[u8]) -> u64 {
+		let table = &self.tables[0];
+		if self.params.reflect_in {
+			data.iter().fold(register, |register, &byte| {
+				table[((register ^ u64::from(byte)) & 0xff) as usize] ^ (register >> 8)
+			})
+		} else {
+			data.iter().fold(register, |register, &byte| {
+				table[((register >> 56) ^ u64::from(byte)) as usize] ^ (register << 8)
+			})
+		}
+	}
+
+	fn update(&self, register: u64, data: &[u8]) -> u64 {
+		let tables = &self.tables;
+		let mut chunks = data.chunks_exact(8);
+		let mut register = register;
+		for chunk in chunks.by_ref() {
+			let mut word = [0u8; 8];
+			word.copy_from_slice(chunk);
+			let bytes = if self.params.reflect_in {
+				(register ^ u64::from_le_bytes(word)).to_le_bytes()
+			} else {
+				(register ^ u64::from_be_bytes(word)).to_be_bytes()
+			};
+			register = bytes
+				.iter()
+				.enumerate()
+				.fold(0, |acc, (k, &byte)| acc ^ tables[7 - k][byte as usize]);
+		}
+		self.update_bytewise(register, chunks.remainder())
+	}
+
+	// slice-by-8
+	pub fn checksum(&self, data: &[u8]) -> u64 {
+		self.finalize(self.update(self.initial_register(), data))
+	}
+
+	pub fn checksum_bytewise(&self, data: &[u8]) -> u64 {
+		self.finalize(self.update_bytewise(self.initial_register(), data))
+	}
+
+	pub fn checksum_bitwise(&self, data: &[u8]) -> u64 {
+		let width = self.params.width;
+		let top = 1 << (width - 1);
+		let mut register = self.params.init;
+		for &byte in data {
+			let byte = if self.params.reflect_in {
+				byte.reverse_bits()
+			} else {
+				byte
+			};
+			for k in (0..8).rev() {
+				let bit = u64::from(byte >> k & 1);
+				let feedback = (register & top != 0) as u64 ^ bit;
+				register = (register << 1) & mask(width);
+				if feedback == 1 {
+					register ^= self.params.poly;
+				}
+			}
+		}
+		if self.params.reflect_out {
+			register = reflect(register, width);
+		}
+		register ^ self.params.xor_out
+	}
+
+	// computes (M(x) · xʷ + init(x) · xⁿ) mod G(x) for a message M(x) of n bits
+	pub fn checksum_polynomial(&self, data: &[u8]) -> u64 {
+		let width = self.params.width;
+		let bits =
+			|value: u64, count: u32| (0..count).rev().map(move |k| ModInt::new(value >> k & 1));
+
+		let message: SmallVec<[ModInt<2>; 8]> = data
+			.iter()
+			.flat_map(|&byte| {
+				let byte = if self.params.reflect_in {
+					byte.reverse_bits()
+				} else {
+					byte
+				};
+				bits(u64::from(byte), 8)
+			})
+			.collect();
+		let message_bits = message.len();
+
+		let shifted_message = Polynomial::new(
+			message
+				.into_iter()
+				.chain((0..width).map(|_| ModInt::zero()))
+				.collect(),
+		);
+		let shifted_init = Polynomial::new(
+			bits(self.params.init, width)
+				.chain((0..message_bits).map(|_| ModInt::zero()))
+				.collect(),
+		);
+		let (_, remainder) = (shifted_message + shifted_init).div_rem(&self.params.generator());
+
+		let mut register = remainder.to_bits();
+		if self.params.reflect_out {
+			register = reflect(register, width);
+		}
+		register ^ self.params.xor_out
+	}
+
+	pub fn digest(&self) -> CrcDigest<'_> {
+		CrcDigest {
+			crc: self,
+			register: self.initial_register(),
+		}
+	}
+}
+
+impl<'a> CrcDigest<'a> {
+	pub fn update(&mut self, data: &[u8]) {
+		self.register = self.crc.update(self.register, data);
+	}
+
+	pub fn finalize(&self) -> u64 {
+		self.crc.finalize(self.register)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	const CHECK_DATA: &[u8] = b"123456789";
+
+	fn bytes(len: usize) -> Vec<u8> {
+		(0..len).map(|k| (k * 131 + k / 7) as u8).collect()
+	}
+
+	#[test]
+	fn test_check_values() {
+		for params in CRC_CATALOGUE.iter() {
+			let crc = Crc::new(*params);
+			assert_eq!(crc.checksum(CHECK_DATA), params.check, "{}", params.name);
+			assert_eq!(
+				crc.checksum_bytewise(CHECK_DATA),
+				params.check,
+				"{}",
+				params.name
+			);
+			assert_eq!(
+				crc.checksum_bitwise(CHECK_DATA),
+				params.check,
+				"{}",
+				params.name
+			);
+			assert_eq!(
+				crc.checksum_polynomial(CHECK_DATA),
+				params.check,
+				"{}",
+				params.name
+			);
+		}
+	}
+
+	#[test]
+	fn test_implementations_agree() {
+		for params in CRC_CATALOGUE.iter() {
+			let crc = Crc::new(*params);
+			for len in [0, 1, 7, 8, 9, 15, 16, 17, 100].iter() {
+				let data = bytes(*len);
+				let expected = crc.checksum_bitwise(&data);
+				assert_eq!(
+					crc.checksum(&data),
+					expected,
+					"{} ({} bytes)",
+					params.name,
+					len
+				);
+				assert_eq!(
+					crc.checksum_bytewise(&data),
+					expected,
+					"{} ({} bytes)",
+					params.name,
+					len
+				);
+				assert_eq!(
+					crc.checksum_polynomial(&data),
+					expected,
+					"{} ({} bytes)",
+					params.name,
+					len
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_digest() {
+		let crc = Crc::new(CRC_32_ISO_HDLC);
+		let data = bytes(1000);
+		let mut digest = crc.digest();
+		for chunk in data.chunks(37) {
+			digest.update(chunk);
+		}
+		assert_eq!(digest.finalize(), crc.checksum(&data));
+	}
+
+	#[test]
+	fn test_generator() {
+		// x¹⁶ + x¹² + x⁵ + 1
+		let generator = CRC_16_XMODEM.generator();
+		assert_eq!(generator.to_display("x").to_string(), "x¹⁶ + x¹² + x⁵ + 1");
+		assert_eq!(CRC_64_XZ.generator().order(), 64);
+	}
+}
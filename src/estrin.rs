@@ -0,0 +1,207 @@
+use crate::Polynomial;
+
+use core::convert::TryInto;
+use core::ops::{AddAssign, Mul, MulAssign};
+use num::{One, Zero};
+
+// number of points evaluated at once; portable SIMD is not available on stable Rust, instead the
+// lanes of a block are stored as arrays, so that the element-wise loops over them are
+// auto-vectorized by the compiler for f32 and f64
+const LANES: usize = 8;
+
+// number of points evaluated by each task in par_eval_many
+#[cfg(feature = "rayon")]
+const PAR_BLOCK: usize = 128 * LANES;
+
+// Estrin's scheme for L points at once, i.e., p(x) = (a₀ + a₁x) + (a₂ + a₃x)x² + … is split into
+// pairs which are combined using x², x⁴, … in a balanced tree of depth ⌈log₂(n + 1)⌉
+// all loops over the lanes are branch-free and independent
+fn estrin_block<T, X, Y, const L: usize>(
+	rev_coeffs: &[T],
+	xs: &[X; L],
+	out: &mut [Y; L],
+	scratch: &mut Vec<[Y; L]>,
+) where
+	for<'l, 'r> &'l T: Mul<&'r X, Output = Y>,
+	X: One + Clone + for<'r> MulAssign<&'r X>,
+	Y: AddAssign + Zero + for<'r> MulAssign<&'r X>,
+{
+	let one = X::one();
+	let mut powers = xs.clone();
+
+	// the scratch buffer stores one partial sum for all lanes per entry
+	scratch.clear();
+	for pair in rev_coeffs.chunks(2) {
+		let mut sum: [Y; L] = core::array::from_fn(|_| &pair[0] * &one);
+		if let Some(a) = pair.get(1) {
+			for (s, x) in sum.iter_mut().zip(xs.iter()) {
+				*s += a * x;
+			}
+		}
+		scratch.push(sum);
+	}
+
+	let mut m = scratch.len();
+	while m > 1 {
+		for x in powers.iter_mut() {
+			let x_old = x.clone();
+			*x *= &x_old;
+		}
+		for i in 0..m / 2 {
+			let mut hi =
+				core::mem::replace(&mut scratch[2 * i + 1], core::array::from_fn(|_| Y::zero()));
+			for (h, x) in hi.iter_mut().zip(powers.iter()) {
+				*h *= x;
+			}
+			for (s, h) in scratch[2 * i].iter_mut().zip(hi) {
+				*s += h;
+			}
+			// the sums previously stored at i have already been consumed
+			scratch.swap(i, 2 * i);
+		}
+		if m % 2 == 1 {
+			scratch.swap(m / 2, m - 1);
+		}
+		m = m / 2 + m % 2;
+	}
+
+	scratch.truncate(1);
+	*out = scratch.pop().unwrap();
+}
+
+impl<T> Polynomial<T> {
+	// evaluates the polynomial at all xs using Estrin's scheme, blocks of LANES points are evaluated
+	// together, remaining points are evaluated one at a time
+	//
+	// the results are independent of the position of each point in xs; for exact types they equal
+	// those of eval, for floating point types both satisfy |y - p(x)| ≤ γ₂ₙ · p̃(|x|), where n is the
+	// order, γₖ = k·u / (1 - k·u) with the unit roundoff u and p̃ has the absolute values of the
+	// coefficients of p, but Estrin's scheme may round differently
+	pub fn eval_many<X, Y>(&self, xs: &[X], out: &mut [Y])
+	where
+		for<'l, 'r> &'l T: Mul<&'r X, Output = Y>,
+		X: One + Clone + for<'r> MulAssign<&'r X>,
+		Y: AddAssign + Zero + for<'r> MulAssign<&'r X>,
+	{
+		assert_eq!(xs.len(), out.len());
+		let pairs = self.rev_coeffs.len() / 2 + self.rev_coeffs.len() % 2;
+		let mut scratch = Vec::with_capacity(pairs);
+		let mut tail_scratch = Vec::new();
+
+		let mut xs_blocks = xs.chunks_exact(LANES);
+		let mut out_blocks = out.chunks_exact_mut(LANES);
+		for (xs, out) in (&mut xs_blocks).zip(&mut out_blocks) {
+			let (xs, out) = (xs.try_into().unwrap(), out.try_into().unwrap());
+			estrin_block::<T, X, Y, LANES>(&self.rev_coeffs, xs, out, &mut scratch);
+		}
+
+		let xs_tail = xs_blocks.remainder().chunks_exact(1);
+		let out_tail = out_blocks.into_remainder().chunks_exact_mut(1);
+		for (xs, out) in xs_tail.zip(out_tail) {
+			let (xs, out) = (xs.try_into().unwrap(), out.try_into().unwrap());
+			estrin_block::<T, X, Y, 1>(&self.rev_coeffs, xs, out, &mut tail_scratch);
+		}
+	}
+
+	// parallel version of eval_many, as the results do not depend on the position of the points,
+	// they are identical to those of eval_many for any number of threads
+	#[cfg(feature = "rayon")]
+	pub fn par_eval_many<X, Y>(&self, xs: &[X], out: &mut [Y])
+	where
+		T: Sync,
+		for<'l, 'r> &'l T: Mul<&'r X, Output = Y>,
+		X: One + Clone + for<'r> MulAssign<&'r X> + Send + Sync,
+		Y: AddAssign + Zero + for<'r> MulAssign<&'r X> + Send,
+	{
+		use rayon::prelude::*;
+
+		assert_eq!(xs.len(), out.len());
+		xs.par_chunks(PAR_BLOCK)
+			.zip(out.par_chunks_mut(PAR_BLOCK))
+			.for_each(|(xs, out)| self.eval_many(xs, out));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	#[test]
+	fn test_eval_many_exact() {
+		let poly = Polynomial::new((1i64..=13).collect());
+		for len in [0usize, 1, 7, 8, 9, 30].iter() {
+			let xs: Vec<i64> = (0..*len as i64).map(|x| x - 10).collect();
+			let mut out = vec![0i64; *len];
+			poly.eval_many(&xs, &mut out);
+			let expected: Vec<i64> = xs.iter().map(|&x| poly.eval(x)).collect();
+			assert_eq!(out, expected);
+		}
+
+		let poly = Polynomial::new(coefficients![5i64]);
+		let mut out = [0i64; 9];
+		poly.eval_many(&[1i64; 9], &mut out);
+		assert_eq!(out, [5i64; 9]);
+	}
+
+	#[test]
+	fn test_eval_many_float() {
+		let poly = Polynomial::new((0..20).map(|k| f64::from(k * 7 % 11) - 5.0).collect());
+		let abs = Polynomial::new(poly.coeffs().iter().map(|a| a.abs()).collect());
+		let xs: Vec<f64> = (0..101).map(|k| f64::from(k) / 25.0 - 2.0).collect();
+		let mut out = vec![0f64; xs.len()];
+		poly.eval_many(&xs, &mut out);
+
+		let u = f64::EPSILON / 2.0;
+		let gamma = 38.0 * u / (1.0 - 38.0 * u);
+		for (&x, &y) in xs.iter().zip(out.iter()) {
+			let horner: f64 = poly.eval(x);
+			let bound: f64 = abs.eval(x.abs());
+			assert!((y - horner).abs() <= 2.0 * gamma * bound);
+		}
+
+		// the tail is evaluated with the same operations as the blocks
+		let mut tail = vec![0f64; 5];
+		poly.eval_many(&xs[8..13], &mut tail);
+		assert_eq!(tail[..], out[8..13]);
+
+		let poly = Polynomial::new(coefficients![1f32, 2.0, 3.0, 0.0]);
+		let mut out = [0f32; 3];
+		poly.eval_many(&[0f32, 1.0, 2.0], &mut out);
+		assert_eq!(out, [0f32, 6.0, 22.0]);
+	}
+
+	#[test]
+	fn test_eval_many_mixed() {
+		let poly = Polynomial::new(coefficients![1f32, 2.0, 3.0, 0.0]);
+		let i = num::Complex::new(0f32, 1.0);
+		let xs = vec![i; 10];
+		let mut out = vec![num::Complex::new(0f32, 0.0); 10];
+		poly.eval_many(&xs, &mut out);
+		assert!(out.iter().all(|&y| y == num::Complex::new(-2f32, 2.0)));
+	}
+
+	#[test]
+	#[should_panic(expected = "assertion `left == right` failed")]
+	fn test_eval_many_length_mismatch() {
+		let poly = Polynomial::new(coefficients![1f32, 2.0]);
+		poly.eval_many(&[1f32, 2.0], &mut [0f32; 3]);
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn test_par_eval_many() {
+		let poly = Polynomial::new((0..50).map(|k| f64::from(k).sin()).collect());
+		let xs: Vec<f64> = (0..5003).map(|k| f64::from(k) / 2500.0 - 1.0).collect();
+		let mut expected = vec![0f64; xs.len()];
+		poly.eval_many(&xs, &mut expected);
+		for &threads in [1, 2, 7].iter() {
+			let pool = rayon::ThreadPoolBuilder::new()
+				.num_threads(threads)
+				.build()
+				.unwrap();
+			let mut out = vec![0f64; xs.len()];
+			pool.install(|| poly.par_eval_many(&xs, &mut out));
+			assert_eq!(out, expected);
+		}
+	}
+}
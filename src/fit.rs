@@ -0,0 +1,300 @@
+use crate::linalg::{least_squares, Matrix};
+use crate::Polynomial;
+
+use smallvec::SmallVec;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolynomialFit {
+	pub polynomial: Polynomial<f64>,
+	// yᵢ - p(xᵢ)
+	pub residuals: Vec<f64>,
+	// Σ wᵢ · (yᵢ - p(xᵢ))²
+	pub weighted_rss: f64,
+	// covariance of the coefficients indexed by their powers, estimated using the residual variance
+	// weighted_rss / (n - m) for n data points with positive weight and m free coefficients, or None
+	// if n ≤ m
+	pub covariance: Option<Vec<Vec<f64>>>,
+}
+
+// q(x) = p((x - center) / half_width)
+pub(crate) fn unscale(p: &Polynomial<f64>, center: f64, half_width: f64) -> Polynomial<f64> {
+	let mut scale = 1.0;
+	let rev_coeffs = p
+		.rev_coeffs
+		.iter()
+		.map(|a| {
+			let v = a * scale;
+			scale /= half_width;
+			v
+		})
+		.collect();
+	Polynomial::new_reversed(rev_coeffs).taylor_expand(&-center)
+}
+
+impl Polynomial<f64> {
+	// weighted least-squares fit of a polynomial of the given degree, see fit_constrained
+	pub fn fit(
+		xs: &[f64],
+		ys: &[f64],
+		degree: usize,
+		weights: Option<&[f64]>,
+	) -> Option<PolynomialFit> {
+		Self::fit_constrained(xs, ys, degree, weights, &[])
+	}
+
+	// minimizes Σ wᵢ · (yᵢ - p(xᵢ))² subject to p(xⱼ) = vⱼ for the constraints (xⱼ, vⱼ) over all
+	// polynomials of the given degree, where the weights default to one
+	//
+	// to avoid the ill-conditioning of the normal equations and of the monomial basis on arbitrary
+	// domains, the points are mapped to [-1, 1] and the problem is solved by Householder QR
+	// decomposition; the constraints are eliminated by writing p = p₀ + q · r, where p₀ interpolates
+	// the constraints and q(x) = ∏ (x - xⱼ), so only r is fitted
+	//
+	// returns None if the problem does not have a unique solution, e.g., if there are too few
+	// distinct points or the constraints do not have distinct x
+	pub fn fit_constrained(
+		xs: &[f64],
+		ys: &[f64],
+		degree: usize,
+		weights: Option<&[f64]>,
+		constraints: &[(f64, f64)],
+	) -> Option<PolynomialFit> {
+		assert_eq!(xs.len(), ys.len());
+		if let Some(weights) = weights {
+			assert!(weights.len() == xs.len() && weights.iter().all(|&w| w >= 0.0));
+		}
+		assert!(constraints.len() <= degree + 1);
+		let n = xs.len();
+		let m = degree + 1 - constraints.len();
+		if n < m {
+			return None;
+		}
+
+		let (lo, hi) = xs
+			.iter()
+			.chain(constraints.iter().map(|(x, _)| x))
+			.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &x| {
+				(lo.min(x), hi.max(x))
+			});
+		let center = if lo <= hi { (lo + hi) / 2.0 } else { 0.0 };
+		let half_width = if lo < hi { (hi - lo) / 2.0 } else { 1.0 };
+		let scale = |x: f64| (x - center) / half_width;
+
+		let (cts, cvs): (Vec<f64>, Vec<f64>) =
+			constraints.iter().map(|&(x, v)| (scale(x), v)).unzip();
+		for (j, t) in cts.iter().enumerate() {
+			if cts[..j].contains(t) {
+				return None;
+			}
+		}
+		let p0 = Polynomial::interpolate(&cts, &cvs);
+		let q = Polynomial::from_roots(&cts);
+
+		let ts: Vec<f64> = xs.iter().cloned().map(scale).collect();
+		let sqrt_weights: Vec<f64> = match weights {
+			Some(weights) => weights.iter().map(|w| w.sqrt()).collect(),
+			None => vec![1.0; n],
+		};
+		let mut a = Matrix::zeros(n, m);
+		let mut b = vec![0.0; n];
+		for i in 0..n {
+			let mut v: f64 = sqrt_weights[i] * q.eval(ts[i]);
+			for j in 0..m {
+				*a.get_mut(i, j) = v;
+				v *= ts[i];
+			}
+			b[i] = sqrt_weights[i] * (ys[i] - p0.eval(ts[i]));
+		}
+		let (r, inverse) = if m > 0 {
+			least_squares(&a, &b)?
+		} else {
+			(Vec::new(), Matrix::zeros(0, 0))
+		};
+
+		let scaled = &p0 + &q * &Polynomial::new_reversed(r.into_iter().collect());
+		let polynomial = unscale(&scaled, center, half_width);
+		let residuals: Vec<f64> = ts
+			.iter()
+			.zip(ys.iter())
+			.map(|(&t, &y)| y - scaled.eval(t))
+			.collect();
+		let weighted_rss = residuals
+			.iter()
+			.zip(sqrt_weights.iter())
+			.map(|(r, w)| (r * w).powi(2))
+			.sum();
+
+		// the coefficients are J · r + const, where column j of J holds the coefficients of q(x) · xʲ
+		// after unscaling, so their covariance is J (AᵀA)⁻¹ Jᵀ σ²
+		// points with zero weight do not contribute to the degrees of freedom
+		let positive = sqrt_weights.iter().filter(|&&w| w > 0.0).count();
+		let covariance = if positive > m {
+			let variance = weighted_rss / (positive - m) as f64;
+			let columns: Vec<Polynomial<f64>> = (0..m)
+				.map(|j| {
+					let mut power: SmallVec<[f64; 8]> = (0..j).map(|_| 0.0).collect();
+					power.push(1.0);
+					unscale(&(&q * &Polynomial::new_reversed(power)), center, half_width)
+				})
+				.collect();
+			let jacobian =
+				|k: usize, j: usize| columns[j].rev_coeffs.get(k).cloned().unwrap_or(0.0);
+			let covariance = (0..=degree)
+				.map(|k| {
+					(0..=degree)
+						.map(|l| {
+							let mut s = 0.0;
+							for i in 0..m {
+								for j in 0..m {
+									s += jacobian(k, i) * inverse.get(i, j) * jacobian(l, j);
+								}
+							}
+							s * variance
+						})
+						.collect()
+				})
+				.collect();
+			Some(covariance)
+		} else {
+			None
+		};
+
+		Some(PolynomialFit {
+			polynomial,
+			residuals,
+			weighted_rss,
+			covariance,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn assert_close(a: f64, b: f64, tolerance: f64) {
+		assert!((a - b).abs() <= tolerance, "{} != {}", a, b);
+	}
+
+	#[test]
+	fn test_fit_exact() {
+		// 2x³ - x + 5 sampled far from the origin, where the monomial basis is badly conditioned
+		let poly = Polynomial::new(coefficients![2f64, 0.0, -1.0, 5.0]);
+		let xs: Vec<f64> = (0..20).map(|k| 100.0 + f64::from(k) * 0.25).collect();
+		let ys: Vec<f64> = xs.iter().map(|&x| poly.eval(x)).collect();
+		let fit = Polynomial::fit(&xs, &ys, 3, None).unwrap();
+		for (a, b) in fit.polynomial.coeffs().iter().zip(poly.coeffs().iter()) {
+			assert_close(*a, *b, 1e-5 * b.abs().max(1.0));
+		}
+		assert!(fit.residuals.iter().all(|r| r.abs() < 1e-6));
+		assert!(fit.weighted_rss < 1e-10);
+
+		// too few points
+		assert!(Polynomial::fit(&xs[..3], &ys[..3], 3, None).is_none());
+		assert!(Polynomial::fit(&[1.0; 5], &[1.0; 5], 1, None).is_none());
+	}
+
+	#[test]
+	fn test_fit_linear_regression() {
+		let xs = [0f64, 1.0, 2.0, 3.0, 4.0];
+		let ys = [1.1f64, 2.9, 5.2, 6.8, 9.0];
+		let fit = Polynomial::fit(&xs, &ys, 1, None).unwrap();
+
+		// closed form of simple linear regression
+		let n = xs.len() as f64;
+		let x_mean = xs.iter().sum::<f64>() / n;
+		let y_mean = ys.iter().sum::<f64>() / n;
+		let sxx: f64 = xs.iter().map(|x| (x - x_mean).powi(2)).sum();
+		let sxy: f64 = xs
+			.iter()
+			.zip(ys.iter())
+			.map(|(x, y)| (x - x_mean) * (y - y_mean))
+			.sum();
+		let slope = sxy / sxx;
+		let intercept = y_mean - slope * x_mean;
+		assert_close(fit.polynomial.coeffs()[0], slope, 1e-12);
+		assert_close(fit.polynomial.coeffs()[1], intercept, 1e-12);
+
+		let rss: f64 = fit.residuals.iter().map(|r| r * r).sum();
+		assert_close(fit.weighted_rss, rss, 1e-12);
+		let variance = rss / (n - 2.0);
+		let covariance = fit.covariance.unwrap();
+		assert_close(covariance[1][1], variance / sxx, 1e-12);
+		assert_close(
+			covariance[0][0],
+			variance * (1.0 / n + x_mean * x_mean / sxx),
+			1e-12,
+		);
+		assert_close(covariance[0][1], -variance * x_mean / sxx, 1e-12);
+		assert_close(covariance[1][0], covariance[0][1], 1e-15);
+
+		// interpolation leaves no degrees of freedom
+		let fit = Polynomial::fit(&xs[..2], &ys[..2], 1, None).unwrap();
+		assert!(fit.covariance.is_none());
+	}
+
+	#[test]
+	fn test_fit_weighted() {
+		// the outlier is ignored with zero weight
+		let xs = [0f64, 1.0, 2.0, 3.0];
+		let ys = [1f64, 3.0, 100.0, 7.0];
+		let weights = [1f64, 1.0, 0.0, 1.0];
+		let fit = Polynomial::fit(&xs, &ys, 1, Some(&weights)).unwrap();
+		assert_close(fit.polynomial.coeffs()[0], 2.0, 1e-12);
+		assert_close(fit.polynomial.coeffs()[1], 1.0, 1e-12);
+		assert_close(fit.residuals[2], 95.0, 1e-12);
+		assert!(fit.weighted_rss < 1e-20);
+
+		// and does not count towards the degrees of freedom of the covariance
+		let ys = [1f64, 3.5, 100.0, 7.0];
+		let weighted = Polynomial::fit(&xs, &ys, 1, Some(&weights)).unwrap();
+		let xs = [0f64, 1.0, 3.0];
+		let ys = [1f64, 3.5, 7.0];
+		let reduced = Polynomial::fit(&xs, &ys, 1, None).unwrap();
+		let (weighted, reduced) = (weighted.covariance.unwrap(), reduced.covariance.unwrap());
+		for (a, b) in weighted.iter().flatten().zip(reduced.iter().flatten()) {
+			assert_close(*a, *b, 1e-12);
+		}
+		let fit = Polynomial::fit(
+			&[0.0, 1.0, 2.0],
+			&[1.0, 3.0, 9.0],
+			1,
+			Some(&[1.0, 1.0, 0.0]),
+		);
+		assert!(fit.unwrap().covariance.is_none());
+	}
+
+	#[test]
+	fn test_fit_constrained() {
+		// a line through the origin minimizes Σ (yᵢ - axᵢ)², i.e., a = Σ xᵢyᵢ / Σ xᵢ²
+		let xs = [1f64, 2.0, 3.0, 4.0];
+		let ys = [3.1f64, 4.9, 7.2, 8.8];
+		let fit = Polynomial::fit_constrained(&xs, &ys, 1, None, &[(0.0, 0.0)]).unwrap();
+		let sxy: f64 = xs.iter().zip(ys.iter()).map(|(x, y)| x * y).sum();
+		let sxx: f64 = xs.iter().map(|x| x * x).sum();
+		assert_close(fit.polynomial.coeffs()[0], sxy / sxx, 1e-12);
+		assert_close(fit.polynomial.coeffs()[1], 0.0, 1e-12);
+
+		// a quadratic through both end points with a fitted curvature
+		let constraints = [(1.0, 3.0), (4.0, 9.0)];
+		let fit = Polynomial::fit_constrained(&xs, &ys, 2, None, &constraints).unwrap();
+		for &(x, v) in constraints.iter() {
+			let y: f64 = fit.polynomial.eval(x);
+			assert_close(y, v, 1e-12);
+		}
+		let covariance = fit.covariance.unwrap();
+		assert_eq!(covariance.len(), 3);
+		assert!(covariance[0][0] > 0.0);
+
+		// fully determined by the constraints
+		let constraints = [(0.0, 1.0), (1.0, 2.0)];
+		let fit = Polynomial::fit_constrained(&xs, &ys, 1, None, &constraints).unwrap();
+		assert_close(fit.polynomial.coeffs()[0], 1.0, 1e-12);
+		assert_close(fit.polynomial.coeffs()[1], 1.0, 1e-12);
+		assert!(fit.covariance.unwrap().iter().flatten().all(|&v| v == 0.0));
+
+		// constraints at the same x
+		let constraints = [(1.0, 2.0), (1.0, 2.0)];
+		assert!(Polynomial::fit_constrained(&xs, &ys, 2, None, &constraints).is_none());
+	}
+}
@@ -0,0 +1,116 @@
+use crate::Polynomial;
+
+use core::ops::{AddAssign, Div, Mul, SubAssign};
+use num::{One, Zero};
+
+impl<T> Polynomial<T> {
+	pub fn monic(&self) -> Self
+	where
+		T: Zero + One,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let inv = &T::one() / self.rev_coeffs.last().unwrap();
+		self * &inv
+	}
+
+	pub fn gcd(&self, rhs: &Self) -> Self
+	where
+		T: Zero
+			+ One
+			+ Clone
+			+ AddAssign
+			+ for<'r> AddAssign<&'r T>
+			+ SubAssign
+			+ for<'r> SubAssign<&'r T>,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let (g, _, _) = self.extended_gcd(rhs);
+		g
+	}
+
+	// returns (g, s, t) such that s · self + t · rhs = g where g is the monic greatest common divisor
+	// (or zero if both self and rhs are zero)
+	pub fn extended_gcd(&self, rhs: &Self) -> (Self, Self, Self)
+	where
+		T: Zero
+			+ One
+			+ Clone
+			+ AddAssign
+			+ for<'r> AddAssign<&'r T>
+			+ SubAssign
+			+ for<'r> SubAssign<&'r T>,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let (mut r0, mut r1) = (self.clone(), rhs.clone());
+		let (mut s0, mut s1) = (Self::one(), Self::zero());
+		let (mut t0, mut t1) = (Self::zero(), Self::one());
+
+		while !r1.is_zero() {
+			let (q, r) = r0.div_rem(&r1);
+			r0 = core::mem::replace(&mut r1, r);
+			let s = &s0 - &(&q * &s1);
+			s0 = core::mem::replace(&mut s1, s);
+			let t = &t0 - &(&q * &t1);
+			t0 = core::mem::replace(&mut t1, t);
+		}
+
+		if r0.is_zero() {
+			return (r0, s0, t0);
+		}
+
+		let inv = &T::one() / r0.rev_coeffs.last().unwrap();
+		(&r0 * &inv, &s0 * &inv, &t0 * &inv)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use num::rational::Rational64;
+	use num::One;
+
+	fn r(v: i64) -> Rational64 {
+		Rational64::from_integer(v)
+	}
+
+	#[test]
+	fn test_monic() {
+		let poly = Polynomial::new(coefficients![r(2), r(1), r(4)]);
+		assert_eq!(
+			poly.monic().coeffs(),
+			coefficients![r(1), Rational64::new(1, 2), r(2)]
+		);
+	}
+
+	#[test]
+	fn test_gcd() {
+		// (x - 1)(x - 2) = x² - 3x + 2
+		let a = Polynomial::new(coefficients![r(1), r(-3), r(2)]);
+		// 2(x - 1)(x - 3) = 2x² - 8x + 6
+		let b = Polynomial::new(coefficients![r(2), r(-8), r(6)]);
+		assert_eq!(a.gcd(&b).coeffs(), coefficients![r(1), r(-1)]);
+
+		let (g, s, t) = a.extended_gcd(&b);
+		assert_eq!(g.coeffs(), coefficients![r(1), r(-1)]);
+		assert_eq!(&s * &a + &t * &b, g);
+	}
+
+	#[test]
+	fn test_gcd_coprime() {
+		// x² + 1 and x - 1 are coprime over ℚ
+		let a = Polynomial::new(coefficients![r(1), r(0), r(1)]);
+		let b = Polynomial::new(coefficients![r(1), r(-1)]);
+		let (g, s, t) = a.extended_gcd(&b);
+		assert!(g.is_one());
+		assert_eq!(&s * &a + &t * &b, g);
+	}
+
+	#[test]
+	fn test_gcd_zero() {
+		let a = Polynomial::new(coefficients![r(2), r(-2)]);
+		let zero = Polynomial::zero();
+		assert_eq!(a.gcd(&zero).coeffs(), coefficients![r(1), r(-1)]);
+		assert_eq!(zero.gcd(&a).coeffs(), coefficients![r(1), r(-1)]);
+		assert!(zero.gcd(&zero).is_zero());
+	}
+}
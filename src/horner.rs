@@ -0,0 +1,143 @@
+use crate::Polynomial;
+
+use num::Float;
+
+// error-free transformations, see Ogita, Rump and Oishi: "Accurate sum and dot product" (2005)
+fn two_sum<T: Float>(a: T, b: T) -> (T, T) {
+	let s = a + b;
+	let z = s - a;
+	(s, (a - (s - z)) + (b - z))
+}
+
+fn two_prod<T: Float>(a: T, b: T) -> (T, T) {
+	let p = a * b;
+	(p, a.mul_add(b, -p))
+}
+
+// γₖ = k·u / (1 - k·u)
+fn gamma<T: Float>(k: usize) -> T {
+	let ku = T::from(k).unwrap() * T::epsilon() / T::from(2).unwrap();
+	ku / (T::one() - ku)
+}
+
+impl<T: Float> Polynomial<T> {
+	// Horner's scheme with a running error bound, see Higham: "Accuracy and Stability of Numerical Algorithms" (2002), algorithm 5.1
+	// returns the value and a bound on its absolute error
+	pub fn eval_with_error_bound(&self, x: T) -> (T, T) {
+		let mut coeffs = self.rev_coeffs.iter().rev();
+		let mut y = *coeffs.next().unwrap();
+		let mut mu = y.abs() / T::from(2).unwrap();
+		for &a in coeffs {
+			y = x * y + a;
+			mu = x.abs() * mu + y.abs();
+		}
+		let u = T::epsilon() / T::from(2).unwrap();
+		(y, u * (T::from(2).unwrap() * mu - y.abs()))
+	}
+
+	// compensated Horner scheme, see Graillat, Langlois and Louvet: "Compensated Horner Scheme" (2005)
+	// the result is as accurate as if computed in twice the working precision and then rounded
+	pub fn eval_compensated(&self, x: T) -> T {
+		self.eval_compensated_with_error_bound(x).0
+	}
+
+	// returns the value and a bound on its absolute error based on the a priori bound
+	// |res - p(x)| ≤ u·|p(x)| + γ²₂ₙ·p̃(|x|), where p̃ has the absolute values of the coefficients of p,
+	// which is inflated slightly to account for rounding in the evaluation of the bound itself
+	pub fn eval_compensated_with_error_bound(&self, x: T) -> (T, T) {
+		let mut coeffs = self.rev_coeffs.iter().rev();
+		let mut s = *coeffs.next().unwrap();
+		let mut c = T::zero();
+		let mut abs = s.abs();
+		for &a in coeffs {
+			let (p, pi) = two_prod(s, x);
+			let (sum, sigma) = two_sum(p, a);
+			s = sum;
+			c = c * x + (pi + sigma);
+			abs = abs * x.abs() + a.abs();
+		}
+		let y = s + c;
+
+		let n = self.rev_coeffs.len() - 1;
+		let u = T::epsilon() / T::from(2).unwrap();
+		let gamma_2n: T = gamma(2 * n);
+		let bound = (u * y.abs() + gamma_2n * gamma_2n * abs)
+			/ (T::one() - T::from(2 * n + 2).unwrap() * u);
+		(y, bound)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use num::Zero;
+
+	// (x - 1)⁸ with exactly representable binomial coefficients
+	fn ill_conditioned() -> Polynomial<f64> {
+		Polynomial::new(coefficients![
+			1f64, -8.0, 28.0, -56.0, 70.0, -56.0, 28.0, -8.0, 1.0
+		])
+	}
+
+	#[test]
+	fn test_eval_horner() {
+		let poly = Polynomial::new(coefficients![1i32, 2, 3, 0]);
+		assert_eq!(poly.eval(2), 22);
+		// (1 + i)³ + 2(1 + i)² + 3(1 + i) = (-2 + 2i) + 4i + (3 + 3i)
+		let x = num::Complex::new(1i32, 1);
+		assert_eq!(poly.eval(x), num::Complex::new(1, 9));
+		assert_eq!(Polynomial::new(coefficients![1i32]).eval(5), 1);
+	}
+
+	#[test]
+	fn test_eval_with_error_bound() {
+		let poly = Polynomial::new(coefficients![1f64, 2.0, 3.0, 0.0]);
+		let (y, bound) = poly.eval_with_error_bound(2.0);
+		assert_eq!(y, 22.0);
+		assert!((0.0..1e-13).contains(&bound));
+
+		let poly = ill_conditioned();
+		for &x in [0.99, 1.001, 1.0001, 1.1].iter() {
+			let exact = (x - 1f64).powi(8);
+			let (y, bound) = poly.eval_with_error_bound(x);
+			assert!((y - exact).abs() <= bound);
+		}
+	}
+
+	#[test]
+	fn test_eval_compensated() {
+		let poly = ill_conditioned();
+		for &x in [0.99, 1.001, 1.0001, 1.1].iter() {
+			// x - 1 is exact, so the reference is accurate to a few ulps
+			let exact = (x - 1f64).powi(8);
+			let (y, bound) = poly.eval_compensated_with_error_bound(x);
+			assert!((y - exact).abs() <= bound);
+			assert_eq!(poly.eval_compensated(x), y);
+		}
+
+		// cond(p, x) ≈ 10²⁶, so plain Horner loses all digits but the compensated scheme still has some
+		let x = 1.001f64;
+		let exact = (x - 1f64).powi(8);
+		let y: f64 = poly.eval(x);
+		assert!((y - exact).abs() / exact > 1.0);
+		let y = poly.eval_compensated(x);
+		assert!((y - exact).abs() / exact < 1e-4);
+
+		// for well-conditioned evaluations the result is correctly rounded
+		let poly = Polynomial::new(coefficients![1f64, 2.0, 3.0, 0.0]);
+		assert_eq!(poly.eval_compensated(2.0), 22.0);
+		assert_eq!(poly.eval_compensated_with_error_bound(0.0), (0.0, 0.0));
+		assert_eq!(Polynomial::<f64>::zero().eval_compensated(3.0), 0.0);
+	}
+
+	#[test]
+	fn test_eval_compensated_f32() {
+		// (x - 1)⁴
+		let poly = Polynomial::new(coefficients![1f32, -4.0, 6.0, -4.0, 1.0]);
+		let x = 1.01f32;
+		let exact = (x - 1f32).powi(4);
+		let (y, bound) = poly.eval_compensated_with_error_bound(x);
+		assert!((y - exact).abs() <= bound);
+		assert!((y - exact).abs() / exact < 1e-3);
+	}
+}
@@ -0,0 +1,353 @@
+use crate::Polynomial;
+
+use core::ops::{Add, Div, Mul, Sub};
+use num::{FromPrimitive, One, Zero};
+use smallvec::SmallVec;
+
+// coefficients of the master polynomial ∏ (x - xᵢ), lowest power first
+fn master_coefficients<T>(xs: &[T]) -> Vec<T>
+where
+	T: Zero + One + Clone + Sub<Output = T> + Mul<Output = T>,
+{
+	let mut coeffs = vec![T::one()];
+	for x in xs {
+		// multiplication by (x - xᵢ)
+		coeffs.push(T::zero());
+		for k in (0..coeffs.len()).rev() {
+			let shifted = if k > 0 {
+				coeffs[k - 1].clone()
+			} else {
+				T::zero()
+			};
+			coeffs[k] = shifted - x.clone() * coeffs[k].clone();
+		}
+	}
+	coeffs
+}
+
+// nested multiplication of the Newton form c₀ + (x - x₀)(c₁ + (x - x₁)(c₂ + …))
+fn newton_to_monomial<T>(differences: Vec<T>, nodes: &[T]) -> Polynomial<T>
+where
+	T: Zero + Clone + Sub<Output = T> + Mul<Output = T>,
+{
+	let mut rev_coeffs: SmallVec<[T; 8]> = SmallVec::with_capacity(nodes.len());
+	for (c, x) in differences.into_iter().zip(nodes.iter()).rev() {
+		rev_coeffs.insert(0, T::zero());
+		for k in 0..rev_coeffs.len() - 1 {
+			let v = rev_coeffs[k].clone() - x.clone() * rev_coeffs[k + 1].clone();
+			rev_coeffs[k] = v;
+		}
+		rev_coeffs[0] = rev_coeffs[0].clone() + c;
+	}
+	Polynomial::new_reversed(rev_coeffs)
+}
+
+impl<T> Polynomial<T> {
+	// returns the unique interpolant of minimal order through the points (xᵢ, yᵢ) using Newton's
+	// divided differences for all types, the xᵢ must be distinct
+	// (the algorithm cannot be selected by type without specialization, so for floating point types,
+	// use BarycentricInterpolant to evaluate the interpolant stably without monomial coefficients, or
+	// interpolate_lagrange to compute the monomial coefficients from the barycentric weights)
+	pub fn interpolate(xs: &[T], ys: &[T]) -> Self
+	where
+		T: Zero + Clone + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+	{
+		assert_eq!(xs.len(), ys.len());
+		let n = xs.len();
+		let mut differences = ys.to_vec();
+		for j in 1..n {
+			for i in (j..n).rev() {
+				let denominator = xs[i].clone() - xs[i - j].clone();
+				assert!(!denominator.is_zero());
+				differences[i] =
+					(differences[i].clone() - differences[i - 1].clone()) / denominator;
+			}
+		}
+
+		newton_to_monomial(differences, xs)
+	}
+
+	// returns the unique polynomial of minimal order p with p⁽ᵏ⁾(xᵢ) = ys[i][k], i.e., ys[i] holds the
+	// value followed by the derivatives to be matched at xᵢ, using confluent divided differences
+	// the xᵢ must be distinct and each ys[i] must not be empty
+	pub fn interpolate_hermite(xs: &[T], ys: &[Vec<T>]) -> Self
+	where
+		T: Zero + Clone + FromPrimitive + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+	{
+		assert_eq!(xs.len(), ys.len());
+		assert!(ys.iter().all(|y| !y.is_empty()));
+
+		// each point is repeated once per prescribed value
+		let groups: Vec<usize> = ys
+			.iter()
+			.enumerate()
+			.flat_map(|(i, y)| (0..y.len()).map(move |_| i))
+			.collect();
+		let nodes: Vec<T> = groups.iter().map(|&i| xs[i].clone()).collect();
+		let n = nodes.len();
+
+		// f[zᵢ₋ⱼ, …, zᵢ] = f⁽ʲ⁾(zᵢ) / j! if all nodes coincide
+		let mut differences: Vec<T> = groups.iter().map(|&i| ys[i][0].clone()).collect();
+		for j in 1..n {
+			for i in (j..n).rev() {
+				differences[i] = if groups[i] == groups[i - j] {
+					(2..=j).fold(ys[groups[i]][j].clone(), |v, k| {
+						v / T::from_usize(k).unwrap()
+					})
+				} else {
+					let denominator = nodes[i].clone() - nodes[i - j].clone();
+					assert!(!denominator.is_zero());
+					(differences[i].clone() - differences[i - 1].clone()) / denominator
+				};
+			}
+		}
+		newton_to_monomial(differences, &nodes)
+	}
+
+	// returns the same interpolant as interpolate, computed as the sum of the Lagrange basis
+	// polynomials yⱼ · ∏ₖ≠ⱼ (x - xₖ) / (xⱼ - xₖ)
+	pub fn interpolate_lagrange(xs: &[T], ys: &[T]) -> Self
+	where
+		T: Zero + One + Clone + PartialEq + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+	{
+		BarycentricInterpolant::new(xs, ys).to_polynomial()
+	}
+}
+
+// interpolant in the barycentric form p(x) = Σ wⱼyⱼ / (x - xⱼ) / Σ wⱼ / (x - xⱼ) with the weights
+// wⱼ = 1 / ∏ₖ≠ⱼ (xⱼ - xₖ), which can be evaluated in O(n) and, unlike the monomial form, is forward
+// stable in floating point for well-distributed points such as Chebyshev points
+#[derive(Clone, Debug, PartialEq)]
+pub struct BarycentricInterpolant<T> {
+	xs: Vec<T>,
+	ys: Vec<T>,
+	weights: Vec<T>,
+}
+
+impl<T> BarycentricInterpolant<T> {
+	pub fn new(xs: &[T], ys: &[T]) -> Self
+	where
+		T: Zero + One + Clone + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+	{
+		assert_eq!(xs.len(), ys.len());
+		let weights = xs
+			.iter()
+			.enumerate()
+			.map(|(j, xj)| {
+				let product = xs
+					.iter()
+					.enumerate()
+					.filter(|&(k, _)| k != j)
+					.fold(T::one(), |p, (_, xk)| p * (xj.clone() - xk.clone()));
+				assert!(!product.is_zero());
+				T::one() / product
+			})
+			.collect();
+		Self {
+			xs: xs.to_vec(),
+			ys: ys.to_vec(),
+			weights,
+		}
+	}
+
+	pub fn points(&self) -> &[T] {
+		&self.xs
+	}
+
+	pub fn values(&self) -> &[T] {
+		&self.ys
+	}
+
+	pub fn weights(&self) -> &[T] {
+		&self.weights
+	}
+
+	pub fn eval(&self, x: T) -> T
+	where
+		T: Zero
+			+ Clone
+			+ PartialEq
+			+ Add<Output = T>
+			+ Sub<Output = T>
+			+ Mul<Output = T>
+			+ Div<Output = T>,
+	{
+		let mut numerator = T::zero();
+		let mut denominator = T::zero();
+		for ((xj, yj), wj) in self.xs.iter().zip(self.ys.iter()).zip(self.weights.iter()) {
+			if x == *xj {
+				return yj.clone();
+			}
+			let t = wj.clone() / (x.clone() - xj.clone());
+			numerator = numerator + t.clone() * yj.clone();
+			denominator = denominator + t;
+		}
+		numerator / denominator
+	}
+
+	// the monomial form Σ wⱼyⱼ · ℓ(x) / (x - xⱼ) with the master polynomial ℓ(x) = ∏ (x - xₖ)
+	pub fn to_polynomial(&self) -> Polynomial<T>
+	where
+		T: Zero + One + Clone + Sub<Output = T> + Mul<Output = T>,
+	{
+		let n = self.xs.len();
+		if n == 0 {
+			return Polynomial::new_reversed(SmallVec::new());
+		}
+		let master = master_coefficients(&self.xs);
+		let mut rev_coeffs: SmallVec<[T; 8]> = (0..n).map(|_| T::zero()).collect();
+		for ((xj, yj), wj) in self.xs.iter().zip(self.ys.iter()).zip(self.weights.iter()) {
+			// synthetic division of ℓ(x) by (x - xⱼ), which has no remainder
+			let scale = wj.clone() * yj.clone();
+			let mut quotient = T::zero();
+			for k in (0..n).rev() {
+				quotient = master[k + 1].clone() + xj.clone() * quotient;
+				let v = core::mem::replace(&mut rev_coeffs[k], T::zero());
+				rev_coeffs[k] = v + scale.clone() * quotient.clone();
+			}
+		}
+		Polynomial::new_reversed(rev_coeffs)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use num::rational::Rational64;
+
+	fn r(n: i64, d: i64) -> Rational64 {
+		Rational64::new(n, d)
+	}
+
+	#[test]
+	fn test_interpolate() {
+		// x³ - 2x + 1/2
+		let poly = Polynomial::new(coefficients![r(1, 1), r(0, 1), r(-2, 1), r(1, 2)]);
+		let xs = [r(-1, 1), r(0, 1), r(1, 3), r(2, 1), r(5, 2)];
+		let ys: Vec<_> = xs.iter().map(|&x| poly.eval(x)).collect();
+		assert_eq!(Polynomial::interpolate(&xs, &ys), poly);
+		assert_eq!(Polynomial::interpolate_lagrange(&xs, &ys), poly);
+		assert_eq!(Polynomial::interpolate(&xs[..4], &ys[..4]), poly);
+
+		// a constant
+		let ys = [r(3, 1); 5];
+		assert_eq!(
+			Polynomial::interpolate(&xs, &ys).coeffs(),
+			coefficients![r(3, 1)]
+		);
+		assert_eq!(
+			Polynomial::interpolate_lagrange(&xs, &ys).coeffs(),
+			coefficients![r(3, 1)]
+		);
+		assert!(Polynomial::<Rational64>::interpolate(&[], &[]).is_zero());
+	}
+
+	#[test]
+	fn test_interpolate_modint() {
+		type F = ModInt<65537>;
+		let xs: Vec<_> = (1..=10u64).map(F::new).collect();
+		let ys: Vec<_> = (1..=10u64)
+			.map(|k| F::new(k * k * k * k * 1000 + 17))
+			.collect();
+		let poly = Polynomial::interpolate(&xs, &ys);
+		assert_eq!(poly, Polynomial::interpolate_lagrange(&xs, &ys));
+		assert!(poly.order() <= 4);
+		for (&x, &y) in xs.iter().zip(ys.iter()) {
+			assert_eq!(poly.eval(x), y);
+		}
+	}
+
+	#[test]
+	fn test_barycentric() {
+		// Chebyshev points of the second kind on [-1, 1]
+		let n = 30;
+		let xs: Vec<f64> = (0..=n)
+			.map(|k| (std::f64::consts::PI * f64::from(k) / f64::from(n)).cos())
+			.collect();
+		let runge = |x: f64| 1.0 / (1.0 + 4.0 * x * x);
+		let ys: Vec<f64> = xs.iter().cloned().map(runge).collect();
+		let interpolant = BarycentricInterpolant::new(&xs, &ys);
+		assert_eq!(interpolant.eval(xs[3]), ys[3]);
+		for k in 0..=100 {
+			let x = f64::from(k) / 50.0 - 1.0;
+			assert!((interpolant.eval(x) - runge(x)).abs() < 1e-5);
+		}
+
+		// the monomial form agrees with it for low orders
+		let xs = [-1f64, -0.5, 0.25, 1.0];
+		let ys = [2f64, -1.0, 0.5, 3.0];
+		let interpolant = BarycentricInterpolant::new(&xs, &ys);
+		let poly = interpolant.to_polynomial();
+		for &x in [-0.75f64, 0.0, 0.5].iter() {
+			let y: f64 = poly.eval(x);
+			assert!((y - interpolant.eval(x)).abs() < 1e-12);
+		}
+		let newton = Polynomial::interpolate(&xs, &ys);
+		for (a, b) in newton.coeffs().iter().zip(poly.coeffs().iter()) {
+			assert!((a - b).abs() < 1e-12);
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "assertion failed: !denominator.is_zero()")]
+	fn test_interpolate_duplicate_points() {
+		let xs = [r(1, 1), r(2, 1), r(1, 1)];
+		let ys = [r(1, 1), r(2, 1), r(3, 1)];
+		Polynomial::interpolate(&xs, &ys);
+	}
+
+	#[test]
+	fn test_interpolate_hermite() {
+		// cubic segment from 0 to 1 starting with velocity 1 and ending at rest: -x³ + x² + x
+		let xs = [r(0, 1), r(1, 1)];
+		let ys = [vec![r(0, 1), r(1, 1)], vec![r(1, 1), r(0, 1)]];
+		assert_eq!(
+			Polynomial::interpolate_hermite(&xs, &ys).coeffs(),
+			coefficients![r(-1, 1), r(1, 1), r(1, 1), r(0, 1)]
+		);
+
+		// position, velocity and acceleration of a quintic at two points, and its value at a third
+		let poly = Polynomial::new([3, -1, 4, 1, -5, 9, 2].iter().map(|&c| r(c, 2)).collect());
+		let values = |x: Rational64, k: usize| -> Vec<Rational64> {
+			(0..k as i32)
+				.map(|j| poly.derivative(j).unwrap().eval(x))
+				.collect()
+		};
+		let xs = [r(-1, 1), r(1, 2), r(2, 1)];
+		let ys = [values(xs[0], 3), values(xs[1], 1), values(xs[2], 3)];
+		assert_eq!(Polynomial::interpolate_hermite(&xs, &ys), poly);
+
+		// a single point gives the Taylor polynomial
+		let ys = [values(r(0, 1), 7)];
+		assert_eq!(Polynomial::interpolate_hermite(&[r(0, 1)], &ys), poly);
+
+		// without derivatives, it is ordinary interpolation
+		let xs = [r(-2, 1), r(0, 1), r(1, 3)];
+		let ys: Vec<_> = xs.iter().map(|&x| vec![poly.eval(x)]).collect();
+		let values: Vec<_> = ys.iter().map(|y| y[0]).collect();
+		assert_eq!(
+			Polynomial::interpolate_hermite(&xs, &ys),
+			Polynomial::interpolate(&xs, &values)
+		);
+	}
+
+	#[test]
+	fn test_interpolate_hermite_float() {
+		// matches sin and its first two derivatives at three points
+		let xs = [0f64, 0.5, 1.0];
+		let ys: Vec<_> = xs
+			.iter()
+			.map(|&x: &f64| vec![x.sin(), x.cos(), -x.sin()])
+			.collect();
+		let poly = Polynomial::interpolate_hermite(&xs, &ys);
+		assert_eq!(poly.order(), 8);
+		for (&x, y) in xs.iter().zip(ys.iter()) {
+			let values = poly.eval_all_derivatives(x, 2).unwrap();
+			for (a, b) in values.iter().zip(y.iter()) {
+				assert!((a - b).abs() < 1e-12);
+			}
+		}
+		let y: f64 = poly.eval(0.75);
+		assert!((y - 0.75f64.sin()).abs() < 1e-9);
+	}
+}
@@ -0,0 +1,342 @@
+use crate::Polynomial;
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use std::fmt::{Display, Formatter};
+
+// closed interval [lo, hi] of f64 values
+//
+// results of arithmetic operations are rounded outward, i.e., they always enclose the exact result
+// of the operation applied to all values in the operands: as the rounding mode cannot be changed,
+// each bound computed with rounding to nearest is moved outward by one ulp
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval {
+	lo: f64,
+	hi: f64,
+}
+
+// the next smaller f64, where the bits of negative values grow with the magnitude
+fn round_down(x: f64) -> f64 {
+	if x.is_nan() || x == f64::NEG_INFINITY {
+		x
+	} else if x == 0.0 {
+		-f64::from_bits(1)
+	} else if x > 0.0 {
+		f64::from_bits(x.to_bits() - 1)
+	} else {
+		f64::from_bits(x.to_bits() + 1)
+	}
+}
+
+fn round_up(x: f64) -> f64 {
+	-round_down(-x)
+}
+
+impl Interval {
+	pub fn new(lo: f64, hi: f64) -> Self {
+		assert!(lo <= hi);
+		Self { lo, hi }
+	}
+
+	pub fn point(x: f64) -> Self {
+		Self::new(x, x)
+	}
+
+	pub fn entire() -> Self {
+		Self::new(f64::NEG_INFINITY, f64::INFINITY)
+	}
+
+	pub fn lo(&self) -> f64 {
+		self.lo
+	}
+
+	pub fn hi(&self) -> f64 {
+		self.hi
+	}
+
+	// the midpoint is not rounded outward, but guaranteed to be contained in the interval
+	pub fn mid(&self) -> f64 {
+		let mid = self.lo / 2.0 + self.hi / 2.0;
+		mid.max(self.lo).min(self.hi)
+	}
+
+	// the width is rounded up
+	pub fn width(&self) -> f64 {
+		round_up(self.hi - self.lo)
+	}
+
+	pub fn contains(&self, x: f64) -> bool {
+		self.lo <= x && x <= self.hi
+	}
+
+	pub fn contains_zero(&self) -> bool {
+		self.contains(0.0)
+	}
+
+	pub fn intersect(&self, rhs: &Self) -> Option<Self> {
+		let lo = self.lo.max(rhs.lo);
+		let hi = self.hi.min(rhs.hi);
+		if lo <= hi {
+			Some(Self { lo, hi })
+		} else {
+			None
+		}
+	}
+
+	fn from_bounds(lo: f64, hi: f64) -> Self {
+		// NaN bounds only arise from ∞ - ∞ or 0 · ∞, in which case nothing is known
+		if lo.is_nan() || hi.is_nan() {
+			Self::entire()
+		} else {
+			Self::new(round_down(lo), round_up(hi))
+		}
+	}
+}
+
+impl Display for Interval {
+	fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+		write!(f, "[{}, {}]", self.lo, self.hi)
+	}
+}
+
+impl From<f64> for Interval {
+	fn from(x: f64) -> Self {
+		Self::point(x)
+	}
+}
+
+impl Neg for Interval {
+	type Output = Interval;
+	fn neg(self) -> Interval {
+		// negation is exact
+		Interval::new(-self.hi, -self.lo)
+	}
+}
+
+impl Add for Interval {
+	type Output = Interval;
+	fn add(self, rhs: Interval) -> Interval {
+		Interval::from_bounds(self.lo + rhs.lo, self.hi + rhs.hi)
+	}
+}
+
+impl Sub for Interval {
+	type Output = Interval;
+	fn sub(self, rhs: Interval) -> Interval {
+		Interval::from_bounds(self.lo - rhs.hi, self.hi - rhs.lo)
+	}
+}
+
+impl Mul for Interval {
+	type Output = Interval;
+	fn mul(self, rhs: Interval) -> Interval {
+		let products = [
+			self.lo * rhs.lo,
+			self.lo * rhs.hi,
+			self.hi * rhs.lo,
+			self.hi * rhs.hi,
+		];
+		if products.iter().any(|p| p.is_nan()) {
+			return Interval::entire();
+		}
+		let lo = products.iter().cloned().fold(f64::INFINITY, f64::min);
+		let hi = products.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+		Interval::from_bounds(lo, hi)
+	}
+}
+
+impl Div for Interval {
+	type Output = Interval;
+	fn div(self, rhs: Interval) -> Interval {
+		assert!(!rhs.contains_zero());
+		let quotients = [
+			self.lo / rhs.lo,
+			self.lo / rhs.hi,
+			self.hi / rhs.lo,
+			self.hi / rhs.hi,
+		];
+		if quotients.iter().any(|q| q.is_nan()) {
+			return Interval::entire();
+		}
+		let lo = quotients.iter().cloned().fold(f64::INFINITY, f64::min);
+		let hi = quotients.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+		Interval::from_bounds(lo, hi)
+	}
+}
+
+fn horner(rev_coeffs: &[Interval], x: Interval) -> Interval {
+	let mut coeffs = rev_coeffs.iter().rev();
+	let mut y = *coeffs.next().unwrap();
+	for &a in coeffs {
+		y = y * x + a;
+	}
+	y
+}
+
+impl Polynomial<f64> {
+	// returns an interval guaranteed to contain p(x) for all x in the given interval, computed as the
+	// intersection of the enclosures given by
+	// * the Horner form, which is exact for monotone terms but suffers from the dependency problem,
+	// * the mean value form p(m) + p'(X) · (X - m), which converges quadratically for narrow intervals,
+	// * the Bernstein form, whose coefficients on X bound the range, which is tight for wide intervals
+	pub fn eval_interval(&self, x: Interval) -> Interval {
+		let coeffs: Vec<Interval> = self
+			.rev_coeffs
+			.iter()
+			.cloned()
+			.map(Interval::point)
+			.collect();
+		let mut range = horner(&coeffs, x);
+		if coeffs.len() == 1 {
+			return range;
+		}
+
+		let derivative: Vec<Interval> = coeffs
+			.iter()
+			.enumerate()
+			.skip(1)
+			.map(|(k, &a)| Interval::point(k as f64) * a)
+			.collect();
+		let m = Interval::point(x.mid());
+		let mean_value = horner(&coeffs, m) + horner(&derivative, x) * (x - m);
+
+		// all enclosures contain the exact range, so they cannot be disjoint
+		range = range.intersect(&mean_value).unwrap();
+		range.intersect(&bernstein_range(&coeffs, x)).unwrap()
+	}
+}
+
+// hull of the Bernstein coefficients of p on [a, b]
+fn bernstein_range(coeffs: &[Interval], x: Interval) -> Interval {
+	let n = coeffs.len() - 1;
+
+	// q(t) = p(a + (b - a)t) for t in [0, 1], where the Taylor shift is computed as in
+	// Polynomial::taylor_expand
+	let a = Interval::point(x.lo);
+	let mut shifted = coeffs.to_vec();
+	for i in 0..n {
+		for j in (i..n).rev() {
+			shifted[j] = shifted[j] + a * shifted[j + 1];
+		}
+	}
+	let width = Interval::point(x.hi) - a;
+	let mut scale = Interval::point(1.0);
+	for c in shifted.iter_mut().skip(1) {
+		scale = scale * width;
+		*c = *c * scale;
+	}
+
+	// bₖ = Σⱼ C(k, j) / C(n, j) · qⱼ, computed by dividing by C(n, j) and summing n times
+	let mut binomial = Interval::point(1.0);
+	for (j, c) in shifted.iter_mut().enumerate().skip(1) {
+		binomial = binomial * Interval::point((n + 1 - j) as f64) / Interval::point(j as f64);
+		*c = *c / binomial;
+	}
+	for j in 1..=n {
+		for k in (j..=n).rev() {
+			shifted[k] = shifted[k] + shifted[k - 1];
+		}
+	}
+
+	let lo = shifted
+		.iter()
+		.map(Interval::lo)
+		.fold(f64::INFINITY, f64::min);
+	let hi = shifted
+		.iter()
+		.map(Interval::hi)
+		.fold(f64::NEG_INFINITY, f64::max);
+	Interval::new(lo, hi)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{round_down, round_up};
+	use crate::*;
+
+	#[test]
+	fn test_rounding() {
+		let tiny = f64::from_bits(1);
+		assert_eq!(round_up(1.0), 1.0 + f64::EPSILON);
+		assert_eq!(round_down(1.0), 1.0 - f64::EPSILON / 2.0);
+		assert_eq!(round_down(-1.0), -1.0 - f64::EPSILON);
+		assert_eq!((round_down(0.0), round_up(0.0)), (-tiny, tiny));
+		assert_eq!((round_down(-0.0), round_up(-0.0)), (-tiny, tiny));
+		assert_eq!((round_down(tiny), round_up(-tiny)), (0.0, 0.0));
+		assert_eq!(round_up(f64::MAX), f64::INFINITY);
+		assert_eq!(round_down(f64::INFINITY), f64::MAX);
+		assert_eq!(round_up(f64::INFINITY), f64::INFINITY);
+		assert_eq!(round_down(f64::NEG_INFINITY), f64::NEG_INFINITY);
+		assert!(round_up(f64::NAN).is_nan());
+	}
+
+	#[test]
+	fn test_interval_arithmetic() {
+		let a = Interval::new(1.0, 2.0);
+		let b = Interval::new(-3.0, 0.5);
+		let c = a * b;
+		assert!(c.lo() <= -6.0 && c.lo() > -6.0 - 1e-14);
+		assert!(c.hi() >= 1.0 && c.hi() < 1.0 + 1e-15);
+		assert!((a + b).contains(-2.0) && (a + b).contains(2.5));
+		assert!((a - b).contains(0.5) && (a - b).contains(5.0));
+		assert_eq!(-a, Interval::new(-2.0, -1.0));
+
+		// 0.1 is not representable, but the enclosure of 1 / 10 contains it either way
+		let tenth = Interval::point(1.0) / Interval::point(10.0);
+		assert!(tenth.contains(0.1));
+		assert!(tenth.lo() < tenth.hi());
+
+		assert_eq!(a.intersect(&b), None);
+		assert_eq!(
+			a.intersect(&Interval::new(1.5, 3.0)),
+			Some(Interval::new(1.5, 2.0))
+		);
+	}
+
+	#[test]
+	fn test_eval_interval() {
+		// x² - 2 on [1, 2] has range [-1, 2]
+		let poly = Polynomial::new(coefficients![1f64, 0.0, -2.0]);
+		let range = poly.eval_interval(Interval::new(1.0, 2.0));
+		assert!(range.contains(-1.0) && range.contains(2.0));
+		assert!(range.width() < 3.0 + 1e-12);
+
+		// no root in [1.5, 2]
+		let range = poly.eval_interval(Interval::new(1.5, 2.0));
+		assert!(range.lo() > 0.0);
+
+		// the range of x² - 2x on [0, 2] is [-1, 0], where the Horner form gives [-4, 0], the mean
+		// value form [-3, 1] and the Bernstein form [-2, 0]
+		let poly = Polynomial::new(coefficients![1f64, -2.0, 0.0]);
+		let range = poly.eval_interval(Interval::new(0.0, 2.0));
+		assert!(range.contains(-1.0) && range.contains(0.0));
+		assert!(range.lo() > -2.0 - 1e-12 && range.hi() < 1e-12);
+
+		let constant = Polynomial::new(coefficients![3f64]);
+		assert_eq!(
+			constant.eval_interval(Interval::new(-1.0, 1.0)),
+			Interval::point(3.0)
+		);
+	}
+
+	#[test]
+	fn test_eval_interval_enclosure() {
+		// (x - 1)⁸ evaluated at dyadic points with few bits is exact
+		let poly = Polynomial::new(coefficients![
+			1f64, -8.0, 28.0, -56.0, 70.0, -56.0, 28.0, -8.0, 1.0
+		]);
+		for &(lo, hi) in [(0.5, 1.5), (0.75, 1.0), (1.0, 1.0), (-2.0, 0.25)].iter() {
+			let range = poly.eval_interval(Interval::new(lo, hi));
+			for k in 0..=64 {
+				let x = lo + (hi - lo) * f64::from(k) / 64.0;
+				assert!(range.contains((x - 1.0).powi(8)));
+			}
+		}
+
+		// the mean value form converges quadratically for narrow intervals around simple points
+		let poly = Polynomial::new(coefficients![0.5f64, -1.25, 3.0, 1.0]);
+		let x = Interval::new(0.3, 0.3 + 1e-9);
+		let range = poly.eval_interval(x);
+		let derivative: f64 = poly.eval_der(0.3, 1);
+		assert!(range.width() < derivative.abs() * 1e-9 * 1.01);
+	}
+}
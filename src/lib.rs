@@ -1,17 +1,58 @@
 use core::convert::TryFrom;
-use core::ops::{AddAssign, Div, Mul, MulAssign, SubAssign};
+use core::ops::{AddAssign, Div, Mul, MulAssign, ShrAssign, SubAssign};
 use num::traits::{MulAddAssign, Pow};
-use num::Zero;
+use num::{Integer, One, Zero};
 use smallvec::SmallVec;
 
 mod add;
+mod bernstein;
+mod bezier;
+mod calculus;
+mod chebyshev;
+mod crc;
 mod display;
+mod estrin;
+mod fit;
+mod gcd;
+mod horner;
+mod interpolation;
+mod interval;
+mod linalg;
+mod modint;
 mod mul;
+mod orthogonal;
+mod quadrature;
+mod quotient;
+mod recurrence;
+mod reed_solomon;
+mod remez;
+mod shamir;
+mod subproduct;
 mod sub;
+// the operator modules only contain trait impls, but are re-exported for any public items they gain
+#[allow(unused_imports)]
 pub use add::*;
+pub use bernstein::*;
+pub use bezier::*;
+pub use chebyshev::*;
+pub use crc::*;
 pub use display::*;
+pub use fit::*;
+pub use interpolation::*;
+pub use interval::*;
+pub use modint::*;
+#[allow(unused_imports)]
 pub use mul::*;
+pub use orthogonal::*;
+pub use quadrature::*;
+pub use quotient::*;
+pub use recurrence::*;
+pub use reed_solomon::*;
+pub use remez::*;
+pub use shamir::*;
+#[allow(unused_imports)]
 pub use sub::*;
+pub use subproduct::*;
 
 #[cfg(debug_assertions)]
 macro_rules! assert_assume {
@@ -129,16 +170,16 @@ impl<T> Polynomial<T> {
 
 	pub fn eval<X, Y>(&self, x: X) -> Y
 	where
-		T: Zero,
 		for<'l, 'r> &'l T: Mul<&'r X, Output = Y>,
-		X: for<'r> MulAssign<&'r X> + num::One,
-		Y: AddAssign + Zero,
+		X: num::One,
+		Y: AddAssign + Zero + for<'r> MulAssign<&'r X>,
 	{
-		let mut xn = X::one();
+		// Horner's scheme, multiplying by one to convert the coefficients allows for mixed types
+		let one = X::one();
 		let mut y = Y::zero();
-		for a in self.rev_coeffs.iter() {
-			y += a * &xn;
-			xn *= &x;
+		for a in self.rev_coeffs.iter().rev() {
+			y *= &x;
+			y += a * &one;
 		}
 		y
 	}
@@ -151,14 +192,12 @@ impl<T> Polynomial<T> {
 		// this trait is only required to guide type inference
 		for<'l> &'l T: Mul<X, Output = Y>,
 	{
+		// Horner's scheme using fused multiply-add
 		let mut y = Y::zero();
-		for (a, e) in self.rev_coeffs.iter().zip(0i32..) {
-			// in num, MulAddAssign is only defined for values not references so we clone a
-			let mut ay = a.clone().into();
-			let mut y_old = Y::zero();
-			core::mem::swap(&mut y, &mut y_old);
-			ay.mul_add_assign(x.pow(e), y_old);
-			core::mem::swap(&mut y, &mut ay);
+		for a in self.rev_coeffs.iter().rev() {
+			// in num, MulAddAssign is only defined for values not references so we clone a, and x¹
+			// provides x by value
+			y.mul_add_assign(x.pow(1), a.clone().into());
 		}
 		y
 	}
@@ -243,6 +282,27 @@ impl<T> Polynomial<T> {
 
 		(Self::new_reversed(quotient), Self::new_reversed(remainder))
 	}
+
+	pub fn pow_mod<E>(&self, exp: E, modulus: &Self) -> Self
+	where
+		T: Zero + One + Clone + AddAssign + for<'r> AddAssign<&'r T> + SubAssign,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+		E: Integer + ShrAssign<usize>,
+	{
+		let mut exp = exp;
+		let (_, mut base) = self.div_rem(modulus);
+		let (_, mut ret) = Self::one().div_rem(modulus);
+		while !exp.is_zero() {
+			if exp.is_odd() {
+				ret = (&ret * &base).div_rem(modulus).1;
+			}
+			exp >>= 1;
+			if !exp.is_zero() {
+				base = (&base * &base).div_rem(modulus).1;
+			}
+		}
+		ret
+	}
 }
 
 #[cfg(test)]
@@ -372,6 +432,29 @@ mod tests {
 		assert_eq!(r, poly_b);
 	}
 
+	#[test]
+	fn test_pow_mod() {
+		let x = Polynomial::new(coefficients![1f64, 0.0]);
+
+		// x⁵ = x (mod x² + 1)
+		let modulus = Polynomial::new(coefficients![1f64, 0.0, 1.0]);
+		assert_eq!(x.pow_mod(5u32, &modulus).coeffs(), coefficients![1f64, 0.0]);
+		assert_eq!(x.pow_mod(6u64, &modulus).coeffs(), coefficients![-1f64]);
+		assert_eq!(x.pow_mod(0u8, &modulus).coeffs(), coefficients![1f64]);
+
+		// (x + 1)³ = x³ + 3x² + 3x + 1 (mod x⁴)
+		let modulus = Polynomial::new(coefficients![1f64, 0.0, 0.0, 0.0, 0.0]);
+		let base = Polynomial::new(coefficients![1f64, 1.0]);
+		assert_eq!(
+			base.pow_mod(num::BigUint::from(3u32), &modulus).coeffs(),
+			coefficients![1f64, 3.0, 3.0, 1.0]
+		);
+
+		// everything is zero modulo a constant
+		let modulus = Polynomial::new(coefficients![2f64]);
+		assert!(base.pow_mod(3u32, &modulus).is_zero());
+	}
+
 	#[test]
 	#[should_panic(expected = "assertion failed: !rhs.is_zero()")]
 	fn test_div_rem_zero() {
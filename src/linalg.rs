@@ -0,0 +1,448 @@
+// dense linear algebra routines used by the numerical algorithms of this crate
+
+use num::Complex;
+
+// row-major dense matrix
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Matrix {
+	pub rows: usize,
+	pub cols: usize,
+	pub data: Vec<f64>,
+}
+
+impl Matrix {
+	pub fn zeros(rows: usize, cols: usize) -> Self {
+		Self {
+			rows,
+			cols,
+			data: vec![0.0; rows * cols],
+		}
+	}
+
+	pub fn get(&self, i: usize, j: usize) -> f64 {
+		self.data[i * self.cols + j]
+	}
+
+	pub fn get_mut(&mut self, i: usize, j: usize) -> &mut f64 {
+		&mut self.data[i * self.cols + j]
+	}
+}
+
+// solves min ‖Ax - b‖₂ for A with at least as many rows as columns using Householder QR
+// returns x and (AᵀA)⁻¹ = R⁻¹R⁻ᵀ, or None if A is numerically rank deficient
+pub(crate) fn least_squares(a: &Matrix, b: &[f64]) -> Option<(Vec<f64>, Matrix)> {
+	let (m, n) = (a.rows, a.cols);
+	assert!(m >= n && b.len() == m);
+	let mut a = a.clone();
+	let mut b = b.to_vec();
+	let scale = a.data.iter().fold(0f64, |s, v| s.max(v.abs()));
+
+	for k in 0..n {
+		let norm = (k..m).map(|i| a.get(i, k).powi(2)).sum::<f64>().sqrt();
+		if norm <= scale * f64::EPSILON * (m as f64) {
+			return None;
+		}
+		let alpha = if a.get(k, k) > 0.0 { -norm } else { norm };
+
+		// H = I - 2vvᵀ / vᵀv with v = aₖ - αeₖ
+		let mut v: Vec<f64> = (k..m).map(|i| a.get(i, k)).collect();
+		v[0] -= alpha;
+		let v_norm2: f64 = v.iter().map(|x| x * x).sum();
+		for j in k + 1..n {
+			let dot: f64 = v.iter().zip(k..m).map(|(vi, i)| vi * a.get(i, j)).sum();
+			let f = 2.0 * dot / v_norm2;
+			for (vi, i) in v.iter().zip(k..m) {
+				*a.get_mut(i, j) -= f * vi;
+			}
+		}
+		let dot: f64 = v.iter().zip(b[k..].iter()).map(|(vi, bi)| vi * bi).sum();
+		let f = 2.0 * dot / v_norm2;
+		for (vi, bi) in v.iter().zip(b[k..].iter_mut()) {
+			*bi -= f * vi;
+		}
+		*a.get_mut(k, k) = alpha;
+		for i in k + 1..m {
+			*a.get_mut(i, k) = 0.0;
+		}
+	}
+
+	// back substitution for Rx = Qᵀb and RX = I
+	let mut x = vec![0.0; n];
+	let mut r_inv = Matrix::zeros(n, n);
+	for i in (0..n).rev() {
+		let s: f64 = (i + 1..n).map(|j| a.get(i, j) * x[j]).sum();
+		x[i] = (b[i] - s) / a.get(i, i);
+
+		*r_inv.get_mut(i, i) = 1.0 / a.get(i, i);
+		for j in i + 1..n {
+			let mut s = 0.0;
+			for k in i + 1..=j {
+				s += a.get(i, k) * r_inv.get(k, j);
+			}
+			*r_inv.get_mut(i, j) = -s / a.get(i, i);
+		}
+	}
+
+	let mut inverse = Matrix::zeros(n, n);
+	for i in 0..n {
+		for j in 0..n {
+			*inverse.get_mut(i, j) = (i.max(j)..n)
+				.map(|k| r_inv.get(i, k) * r_inv.get(j, k))
+				.sum();
+		}
+	}
+	Some((x, inverse))
+}
+
+// eigenvalues of an upper Hessenberg matrix using the Francis double-shift QR algorithm after
+// balancing, see Press et al.: "Numerical Recipes" (2007), sections 11.6 and 11.7
+// returns None if the iteration does not converge
+// (indexing follows the reference implementation, which causes clippy to emit spurious warnings)
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn hessenberg_eigenvalues(matrix: &Matrix) -> Option<Vec<Complex<f64>>> {
+	let n = matrix.rows;
+	assert_eq!(n, matrix.cols);
+	let mut a: Vec<Vec<f64>> = (0..n)
+		.map(|i| (0..n).map(|j| matrix.get(i, j)).collect())
+		.collect();
+
+	// balancing by powers of two is a similarity transformation that preserves the Hessenberg form
+	let mut converged = false;
+	while !converged {
+		converged = true;
+		for i in 0..n {
+			let mut c: f64 = (0..n).filter(|&j| j != i).map(|j| a[j][i].abs()).sum();
+			let r: f64 = (0..n).filter(|&j| j != i).map(|j| a[i][j].abs()).sum();
+			if c == 0.0 || r == 0.0 {
+				continue;
+			}
+			let s = c + r;
+			let mut f = 1.0;
+			while c < r / 2.0 {
+				f *= 2.0;
+				c *= 4.0;
+			}
+			while c > r * 2.0 {
+				f /= 2.0;
+				c /= 4.0;
+			}
+			if (c + r) / f < 0.95 * s {
+				converged = false;
+				for j in 0..n {
+					a[i][j] /= f;
+					a[j][i] *= f;
+				}
+			}
+		}
+	}
+
+	let mut eigenvalues = vec![Complex::new(0.0, 0.0); n];
+	let norm: f64 = (0..n)
+		.map(|i| (i.saturating_sub(1)..n).map(|j| a[i][j].abs()).sum::<f64>())
+		.sum();
+	let mut nn = n as isize - 1;
+	let mut t = 0.0;
+	while nn >= 0 {
+		let mut iterations = 0;
+		loop {
+			let u = nn as usize;
+			// look for a single small subdiagonal element
+			let mut l = u;
+			while l >= 1 {
+				let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+				if s == 0.0 {
+					s = norm;
+				}
+				if a[l][l - 1].abs() + s == s {
+					a[l][l - 1] = 0.0;
+					break;
+				}
+				l -= 1;
+			}
+
+			let mut x = a[u][u];
+			if l == u {
+				// one root found
+				eigenvalues[u] = Complex::new(x + t, 0.0);
+				nn -= 1;
+				break;
+			}
+			let mut y = a[u - 1][u - 1];
+			let mut w = a[u][u - 1] * a[u - 1][u];
+			if l == u - 1 {
+				// two roots found
+				let p = 0.5 * (y - x);
+				let q = p * p + w;
+				let z = q.abs().sqrt();
+				x += t;
+				if q >= 0.0 {
+					let z = p + z.copysign(p);
+					eigenvalues[u - 1] = Complex::new(x + z, 0.0);
+					eigenvalues[u] = Complex::new(if z != 0.0 { x - w / z } else { x + z }, 0.0);
+				} else {
+					eigenvalues[u - 1] = Complex::new(x + p, -z);
+					eigenvalues[u] = Complex::new(x + p, z);
+				}
+				nn -= 2;
+				break;
+			}
+
+			if iterations == 60 {
+				return None;
+			}
+			if iterations == 10 || iterations == 20 {
+				// exceptional shift
+				t += x;
+				for (i, row) in a.iter_mut().enumerate().take(u + 1) {
+					row[i] -= x;
+				}
+				let s = a[u][u - 1].abs() + a[u - 1][u - 2].abs();
+				x = 0.75 * s;
+				y = x;
+				w = -0.4375 * s * s;
+			}
+			iterations += 1;
+
+			// form the shift and look for two consecutive small subdiagonal elements
+			let mut m = u - 2;
+			let (mut p, mut q, mut r);
+			loop {
+				let z = a[m][m];
+				let rr = x - z;
+				let ss = y - z;
+				p = (rr * ss - w) / a[m + 1][m] + a[m][m + 1];
+				q = a[m + 1][m + 1] - z - rr - ss;
+				r = a[m + 2][m + 1];
+				let s = p.abs() + q.abs() + r.abs();
+				p /= s;
+				q /= s;
+				r /= s;
+				if m == l {
+					break;
+				}
+				let uu = a[m][m - 1].abs() * (q.abs() + r.abs());
+				let vv = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+				if uu + vv == vv {
+					break;
+				}
+				m -= 1;
+			}
+			for i in m + 2..=u {
+				a[i][i - 2] = 0.0;
+				if i != m + 2 {
+					a[i][i - 3] = 0.0;
+				}
+			}
+
+			// double QR step on rows l to u and columns m to u
+			for k in m..u {
+				if k != m {
+					p = a[k][k - 1];
+					q = a[k + 1][k - 1];
+					r = if k != u - 1 { a[k + 2][k - 1] } else { 0.0 };
+					x = p.abs() + q.abs() + r.abs();
+					if x != 0.0 {
+						p /= x;
+						q /= x;
+						r /= x;
+					}
+				}
+				let s = (p * p + q * q + r * r).sqrt().copysign(p);
+				if s == 0.0 {
+					continue;
+				}
+				if k == m {
+					if l != m {
+						a[k][k - 1] = -a[k][k - 1];
+					}
+				} else {
+					a[k][k - 1] = -s * x;
+				}
+				p += s;
+				x = p / s;
+				y = q / s;
+				let z = r / s;
+				q /= p;
+				r /= p;
+				for j in k..=u {
+					let mut p = a[k][j] + q * a[k + 1][j];
+					if k != u - 1 {
+						p += r * a[k + 2][j];
+						a[k + 2][j] -= p * z;
+					}
+					a[k + 1][j] -= p * y;
+					a[k][j] -= p * x;
+				}
+				for row in a.iter_mut().take(u.min(k + 3) + 1).skip(l) {
+					let mut p = x * row[k] + y * row[k + 1];
+					if k != u - 1 {
+						p += z * row[k + 2];
+						row[k + 2] -= p * r;
+					}
+					row[k + 1] -= p * q;
+					row[k] -= p;
+				}
+			}
+		}
+	}
+	Some(eigenvalues)
+}
+
+// eigenvalues of the symmetric tridiagonal matrix with the given diagonal and off-diagonal along with
+// the first components of the corresponding normalized eigenvectors, using the QL algorithm with
+// implicit shifts, see Press et al.: "Numerical Recipes" (2007), section 11.4
+// returns None if the iteration does not converge
+pub(crate) fn symmetric_tridiagonal_eigen(
+	diagonal: &[f64],
+	off_diagonal: &[f64],
+) -> Option<(Vec<f64>, Vec<f64>)> {
+	let n = diagonal.len();
+	assert_eq!(off_diagonal.len() + 1, n.max(1));
+	let mut d = diagonal.to_vec();
+	let mut e = off_diagonal.to_vec();
+	e.push(0.0);
+	// the first row of the accumulated rotations
+	let mut z = vec![0.0; n];
+	if n > 0 {
+		z[0] = 1.0;
+	}
+
+	for l in 0..n {
+		let mut iterations = 0;
+		loop {
+			let mut m = l;
+			while m + 1 < n {
+				let dd = d[m].abs() + d[m + 1].abs();
+				if e[m].abs() <= f64::EPSILON * dd {
+					break;
+				}
+				m += 1;
+			}
+			if m == l {
+				break;
+			}
+			iterations += 1;
+			if iterations == 60 {
+				return None;
+			}
+
+			let mut g = (d[l + 1] - d[l]) / (2.0 * e[l]);
+			let mut r = g.hypot(1.0);
+			g = d[m] - d[l] + e[l] / (g + if g >= 0.0 { r } else { -r });
+			let (mut s, mut c, mut p) = (1.0, 1.0, 0.0);
+			let mut underflow = false;
+			for i in (l..m).rev() {
+				let f = s * e[i];
+				let b = c * e[i];
+				r = f.hypot(g);
+				e[i + 1] = r;
+				if r == 0.0 {
+					d[i + 1] -= p;
+					e[m] = 0.0;
+					underflow = true;
+					break;
+				}
+				s = f / r;
+				c = g / r;
+				g = d[i + 1] - p;
+				r = (d[i] - g) * s + 2.0 * c * b;
+				p = s * r;
+				d[i + 1] = g + p;
+				g = c * r - b;
+				let f = z[i + 1];
+				z[i + 1] = s * z[i] + c * f;
+				z[i] = c * z[i] - s * f;
+			}
+			if underflow {
+				continue;
+			}
+			d[l] -= p;
+			e[l] = g;
+			e[m] = 0.0;
+		}
+	}
+	Some((d, z))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_least_squares() {
+		// overdetermined consistent system
+		let a = Matrix {
+			rows: 3,
+			cols: 2,
+			data: vec![1.0, 1.0, 1.0, 2.0, 1.0, 3.0],
+		};
+		let (x, inverse) = least_squares(&a, &[3.0, 5.0, 7.0]).unwrap();
+		assert!((x[0] - 1.0).abs() < 1e-12 && (x[1] - 2.0).abs() < 1e-12);
+		// (AᵀA)⁻¹ = [[3, 6], [6, 14]]⁻¹ = [[14, -6], [-6, 3]] / 6
+		let expected = [14.0 / 6.0, -1.0, -1.0, 0.5];
+		for (v, e) in inverse.data.iter().zip(expected.iter()) {
+			assert!((v - e).abs() < 1e-12);
+		}
+
+		let singular = Matrix {
+			rows: 3,
+			cols: 2,
+			data: vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0],
+		};
+		assert!(least_squares(&singular, &[1.0, 2.0, 3.0]).is_none());
+	}
+
+	#[test]
+	fn test_hessenberg_eigenvalues() {
+		// companion matrix of (x - 1)(x - 2)(x² + 1) = x⁴ - 3x³ + 3x² - 3x + 2
+		let a = Matrix {
+			rows: 4,
+			cols: 4,
+			data: vec![
+				3.0, -3.0, 3.0, -2.0, //
+				1.0, 0.0, 0.0, 0.0, //
+				0.0, 1.0, 0.0, 0.0, //
+				0.0, 0.0, 1.0, 0.0,
+			],
+		};
+		let mut eigenvalues = hessenberg_eigenvalues(&a).unwrap();
+		eigenvalues.sort_by(|a, b| (a.re, a.im).partial_cmp(&(b.re, b.im)).unwrap());
+		let expected = [
+			Complex::new(0.0, -1.0),
+			Complex::new(0.0, 1.0),
+			Complex::new(1.0, 0.0),
+			Complex::new(2.0, 0.0),
+		];
+		for (e, x) in eigenvalues.iter().zip(expected.iter()) {
+			assert!((e - x).norm() < 1e-12);
+		}
+
+		let a = Matrix {
+			rows: 1,
+			cols: 1,
+			data: vec![5.0],
+		};
+		assert_eq!(
+			hessenberg_eigenvalues(&a).unwrap(),
+			vec![Complex::new(5.0, 0.0)]
+		);
+	}
+
+	#[test]
+	fn test_symmetric_tridiagonal_eigen() {
+		// [[2, 1, 0], [1, 2, 1], [0, 1, 2]] has eigenvalues 2 - √2, 2, 2 + √2 with eigenvectors
+		// (1, ∓√2, 1) / 2 and (1, 0, -1) / √2
+		let (values, first) = symmetric_tridiagonal_eigen(&[2.0, 2.0, 2.0], &[1.0, 1.0]).unwrap();
+		let mut pairs: Vec<(f64, f64)> = values.into_iter().zip(first).collect();
+		pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+		let sqrt2 = 2f64.sqrt();
+		let expected = [(2.0 - sqrt2, 0.25), (2.0, 0.5), (2.0 + sqrt2, 0.25)];
+		for ((value, z), (e, z2)) in pairs.iter().zip(expected.iter()) {
+			assert!((value - e).abs() < 1e-14);
+			assert!((z * z - z2).abs() < 1e-14);
+		}
+
+		let (values, first) = symmetric_tridiagonal_eigen(&[5.0], &[]).unwrap();
+		assert_eq!((values, first), (vec![5.0], vec![1.0]));
+	}
+}
@@ -0,0 +1,282 @@
+use crate::Polynomial;
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use num::{FromPrimitive, One, Zero};
+use smallvec::SmallVec;
+use std::fmt::{Display, Formatter};
+
+// integers modulo a prime P, i.e., the prime field GF(P)
+// P must be prime for division to be well-defined, as inverses are computed via Fermat's little theorem
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModInt<const P: u64> {
+	value: u64,
+}
+
+impl<const P: u64> ModInt<P> {
+	pub fn new(value: u64) -> Self {
+		Self { value: value % P }
+	}
+
+	pub fn value(self) -> u64 {
+		self.value
+	}
+
+	pub fn modulus() -> u64 {
+		P
+	}
+
+	pub fn pow(self, exp: u64) -> Self {
+		let mut base = self;
+		let mut exp = exp;
+		let mut ret = Self::one();
+		while exp > 0 {
+			if exp & 1 == 1 {
+				ret *= base;
+			}
+			base *= base;
+			exp >>= 1;
+		}
+		ret
+	}
+
+	pub fn inv(self) -> Self {
+		assert!(!self.is_zero());
+		self.pow(P - 2)
+	}
+}
+
+impl<const P: u64> AddAssign<&ModInt<P>> for ModInt<P> {
+	fn add_assign(&mut self, rhs: &ModInt<P>) {
+		self.value = ((u128::from(self.value) + u128::from(rhs.value)) % u128::from(P)) as u64;
+	}
+}
+
+impl<const P: u64> SubAssign<&ModInt<P>> for ModInt<P> {
+	fn sub_assign(&mut self, rhs: &ModInt<P>) {
+		self.value = ((u128::from(self.value) + u128::from(P - rhs.value)) % u128::from(P)) as u64;
+	}
+}
+
+impl<const P: u64> MulAssign<&ModInt<P>> for ModInt<P> {
+	fn mul_assign(&mut self, rhs: &ModInt<P>) {
+		self.value = ((u128::from(self.value) * u128::from(rhs.value)) % u128::from(P)) as u64;
+	}
+}
+
+impl<const P: u64> DivAssign<&ModInt<P>> for ModInt<P> {
+	// division is multiplication by the inverse, which causes clippy to emit a spurious warning
+	#[allow(clippy::suspicious_op_assign_impl)]
+	fn div_assign(&mut self, rhs: &ModInt<P>) {
+		*self *= &rhs.inv();
+	}
+}
+
+macro_rules! forward_binary_op {
+	($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+		impl<const P: u64> $OpAssign<ModInt<P>> for ModInt<P> {
+			fn $op_assign(&mut self, rhs: ModInt<P>) {
+				self.$op_assign(&rhs);
+			}
+		}
+
+		impl<const P: u64> $Op<ModInt<P>> for ModInt<P> {
+			type Output = ModInt<P>;
+			fn $op(self, rhs: ModInt<P>) -> ModInt<P> {
+				let mut ret = self;
+				ret.$op_assign(&rhs);
+				ret
+			}
+		}
+
+		impl<const P: u64> $Op<&ModInt<P>> for ModInt<P> {
+			type Output = ModInt<P>;
+			fn $op(self, rhs: &ModInt<P>) -> ModInt<P> {
+				let mut ret = self;
+				ret.$op_assign(rhs);
+				ret
+			}
+		}
+
+		impl<const P: u64> $Op<ModInt<P>> for &ModInt<P> {
+			type Output = ModInt<P>;
+			fn $op(self, rhs: ModInt<P>) -> ModInt<P> {
+				let mut ret = *self;
+				ret.$op_assign(&rhs);
+				ret
+			}
+		}
+
+		impl<const P: u64> $Op<&ModInt<P>> for &ModInt<P> {
+			type Output = ModInt<P>;
+			fn $op(self, rhs: &ModInt<P>) -> ModInt<P> {
+				let mut ret = *self;
+				ret.$op_assign(rhs);
+				ret
+			}
+		}
+	};
+}
+
+forward_binary_op!(Add, add, AddAssign, add_assign);
+forward_binary_op!(Sub, sub, SubAssign, sub_assign);
+forward_binary_op!(Mul, mul, MulAssign, mul_assign);
+forward_binary_op!(Div, div, DivAssign, div_assign);
+
+impl<const P: u64> Neg for ModInt<P> {
+	type Output = ModInt<P>;
+	fn neg(self) -> ModInt<P> {
+		Self::zero() - self
+	}
+}
+
+impl<const P: u64> Neg for &ModInt<P> {
+	type Output = ModInt<P>;
+	fn neg(self) -> ModInt<P> {
+		-*self
+	}
+}
+
+impl<const P: u64> Zero for ModInt<P> {
+	fn zero() -> Self {
+		Self { value: 0 }
+	}
+
+	fn is_zero(&self) -> bool {
+		self.value == 0
+	}
+}
+
+impl<const P: u64> One for ModInt<P> {
+	fn one() -> Self {
+		Self::new(1)
+	}
+}
+
+impl<const P: u64> FromPrimitive for ModInt<P> {
+	fn from_i64(n: i64) -> Option<Self> {
+		Self::from_i128(i128::from(n))
+	}
+
+	fn from_u64(n: u64) -> Option<Self> {
+		Some(Self::new(n))
+	}
+
+	fn from_i128(n: i128) -> Option<Self> {
+		Some(Self::new(n.rem_euclid(i128::from(P)) as u64))
+	}
+
+	fn from_u128(n: u128) -> Option<Self> {
+		Some(Self::new((n % u128::from(P)) as u64))
+	}
+
+	// integral values are reduced exactly, fractional values are not representable
+	fn from_f64(n: f64) -> Option<Self> {
+		if !n.is_finite() || n.fract() != 0.0 {
+			return None;
+		}
+		// |n| = m · 2ᵉ with an integer m < 2⁶⁴, halving is exact as integers of at least 2⁵³ are even
+		let (mut m, mut e) = (n.abs(), 0);
+		while m >= 2f64.powi(64) {
+			m /= 2.0;
+			e += 1;
+		}
+		let value = Self::new(m as u64) * Self::new(2).pow(e);
+		Some(if n < 0.0 { -value } else { value })
+	}
+}
+
+impl<const P: u64> Display for ModInt<P> {
+	fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+		write!(f, "{}", self.value)
+	}
+}
+
+// polynomials over GF(2) are commonly written as bit patterns, with bit k holding the coefficient of xᵏ
+impl Polynomial<ModInt<2>> {
+	pub fn from_bits(bits: u64) -> Self {
+		let rev_coeffs: SmallVec<[_; 8]> = (0..64 - bits.leading_zeros())
+			.map(|k| ModInt::new(bits >> k & 1))
+			.collect();
+		Self::new_reversed(rev_coeffs)
+	}
+
+	pub fn to_bits(&self) -> u64 {
+		assert!(self.order() < 64);
+		self.rev_coeffs
+			.iter()
+			.enumerate()
+			.fold(0, |bits, (k, v)| bits | v.value() << k)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use num::{FromPrimitive, One, Zero};
+
+	type F7 = ModInt<7>;
+
+	#[test]
+	fn test_modint_arithmetic() {
+		let a = F7::new(5);
+		let b = F7::new(4);
+		assert_eq!((a + b).value(), 2);
+		assert_eq!((a - b).value(), 1);
+		assert_eq!((b - a).value(), 6);
+		assert_eq!((a * b).value(), 6);
+		assert_eq!((a / b * b), a);
+		assert_eq!((-a).value(), 2);
+		assert_eq!(F7::new(12), F7::new(5));
+		assert_eq!(F7::from_i32(-1), Some(F7::new(6)));
+		assert_eq!(F7::from_u128(1 << 100), Some(F7::new(2)));
+		assert_eq!(F7::from_i128(-(1 << 100)), Some(F7::new(5)));
+		assert_eq!(F7::from_f64(2f64.powi(100)), Some(F7::new(2)));
+		assert_eq!(F7::from_f64(-10.0), Some(F7::new(4)));
+		assert_eq!(F7::from_f64(0.5), None);
+
+		for v in 1..7 {
+			let v = F7::new(v);
+			assert!((v * v.inv()).is_one());
+		}
+	}
+
+	#[test]
+	fn test_modint_large_modulus() {
+		type F = ModInt<0xffff_ffff_ffff_ffc5>; // 2⁶⁴ - 59
+		let a = F::new(0xffff_ffff_ffff_ffc4);
+		assert_eq!(a * a, F::one());
+		assert_eq!(a + a, F::new(0xffff_ffff_ffff_ffc3));
+		assert!((a * a.inv()).is_one());
+	}
+
+	#[test]
+	#[should_panic(expected = "assertion failed: !self.is_zero()")]
+	fn test_modint_inv_zero() {
+		let _ = F7::zero().inv();
+	}
+
+	#[test]
+	fn test_modint_polynomial() {
+		// (x³ + 2x² + 3x) / (x² + 1) = x + 2 | 2x + 5 over GF(7)
+		let a = Polynomial::new(coefficients![
+			F7::new(1),
+			F7::new(2),
+			F7::new(3),
+			F7::new(0)
+		]);
+		let b = Polynomial::new(coefficients![F7::new(1), F7::new(0), F7::new(1)]);
+		let (q, r) = a.div_rem(&b);
+		assert_eq!(q.coeffs(), coefficients![F7::new(1), F7::new(2)]);
+		assert_eq!(r.coeffs(), coefficients![F7::new(2), F7::new(5)]);
+		assert_eq!(q * b + r, a);
+	}
+
+	#[test]
+	fn test_binary_polynomial_bits() {
+		let poly = Polynomial::<ModInt<2>>::from_bits(0x11d);
+		assert_eq!(poly.order(), 8);
+		assert_eq!(poly.to_display("x").to_string(), "x⁸ + x⁴ + x³ + x² + 1");
+		assert_eq!(poly.to_bits(), 0x11d);
+		assert!(Polynomial::<ModInt<2>>::from_bits(0).is_zero());
+	}
+}
@@ -1,7 +1,7 @@
 use crate::Polynomial;
 
 use core::convert::TryFrom;
-use core::ops::{AddAssign, Mul, MulAssign};
+use core::ops::{AddAssign, Mul, MulAssign, Neg};
 use num::{One, Zero};
 use smallvec::{smallvec, SmallVec};
 
@@ -254,6 +254,105 @@ where
 	}
 }
 
+// number of output coefficients computed by each task in par_mul
+#[cfg(feature = "rayon")]
+const PAR_MUL_BLOCK: usize = 256;
+
+// number of roots below which par_from_roots does not split into parallel tasks
+#[cfg(feature = "rayon")]
+const PAR_ROOTS_BLOCK: usize = 64;
+
+// coefficient e of the product, accumulated in the same order as by the Mul implementation
+fn product_coefficient<T, F>(lhs: &[T], rhs: &[T], e: usize, mul: &F) -> T
+where
+	T: Zero + AddAssign,
+	F: Fn(&T, &T) -> T,
+{
+	let mut v = T::zero();
+	for el in (e + 1).saturating_sub(rhs.len())..=e.min(lhs.len() - 1) {
+		v += mul(&lhs[el], &rhs[e - el]);
+	}
+	v
+}
+
+fn product<T, F>(lhs: &[T], rhs: &[T], mul: F) -> Polynomial<T>
+where
+	T: Zero + AddAssign,
+	F: Fn(&T, &T) -> T,
+{
+	let num_coeffs = lhs.len().checked_add(rhs.len() - 1).unwrap();
+	Polynomial::new_reversed(
+		(0..num_coeffs)
+			.map(|e| product_coefficient(lhs, rhs, e, &mul))
+			.collect(),
+	)
+}
+
+// the output is split into blocks of fixed size, so the result is identical to that of product
+// for any number of threads
+#[cfg(feature = "rayon")]
+fn par_product<T, F>(lhs: &[T], rhs: &[T], mul: F) -> Polynomial<T>
+where
+	T: Zero + AddAssign + Send + Sync,
+	F: Fn(&T, &T) -> T + Sync,
+{
+	use rayon::prelude::*;
+
+	let num_coeffs = lhs.len().checked_add(rhs.len() - 1).unwrap();
+	let mut rev_coeffs: Vec<T> = (0..num_coeffs).map(|_| T::zero()).collect();
+	rev_coeffs
+		.par_chunks_mut(PAR_MUL_BLOCK)
+		.enumerate()
+		.for_each(|(block, coeffs)| {
+			for (k, v) in coeffs.iter_mut().enumerate() {
+				*v = product_coefficient(lhs, rhs, block * PAR_MUL_BLOCK + k, &mul);
+			}
+		});
+	Polynomial::new_reversed(SmallVec::from_vec(rev_coeffs))
+}
+
+impl<T> Polynomial<T> {
+	// returns the monic polynomial ∏ (x - rᵢ), computed using a balanced product tree
+	pub fn from_roots(roots: &[T]) -> Self
+	where
+		T: Zero + One + Clone + AddAssign + Neg<Output = T> + Mul<Output = T>,
+	{
+		match roots.len() {
+			0 => Self::new_reversed(smallvec![T::one()]),
+			1 => Self::new_reversed(smallvec![-roots[0].clone(), T::one()]),
+			n => {
+				let (lo, hi) = roots.split_at(n / 2);
+				let (lo, hi) = (Self::from_roots(lo), Self::from_roots(hi));
+				product(&lo.rev_coeffs, &hi.rev_coeffs, |a, b| a.clone() * b.clone())
+			}
+		}
+	}
+
+	// parallel version of Mul, the result is identical to it for any number of threads
+	#[cfg(feature = "rayon")]
+	pub fn par_mul(&self, rhs: &Self) -> Self
+	where
+		T: Zero + AddAssign + Send + Sync,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T>,
+	{
+		par_product(&self.rev_coeffs, &rhs.rev_coeffs, |a, b| a * b)
+	}
+
+	// parallel version of from_roots, uses the same product tree and therefore gives identical results
+	#[cfg(feature = "rayon")]
+	pub fn par_from_roots(roots: &[T]) -> Self
+	where
+		T: Zero + One + Clone + AddAssign + Neg<Output = T> + Mul<Output = T> + Send + Sync,
+	{
+		if roots.len() < PAR_ROOTS_BLOCK {
+			return Self::from_roots(roots);
+		}
+		let (lo, hi) = roots.split_at(roots.len() / 2);
+		let (lo, hi) = rayon::join(|| Self::par_from_roots(lo), || Self::par_from_roots(hi));
+		par_product(&lo.rev_coeffs, &hi.rev_coeffs, |a, b| a.clone() * b.clone())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::*;
@@ -335,6 +434,8 @@ mod tests {
 	}
 
 	#[test]
+	// references are taken deliberately to test all operator implementations
+	#[allow(clippy::op_ref)]
 	fn test_mul_scalar() {
 		let a = Polynomial::new(coefficients![1f32, 3.0, 3.0, 0.0]);
 
@@ -385,4 +486,59 @@ mod tests {
 		assert_eq!(c.order(), 0);
 		assert_eq!(c.coeffs(), coefficients![0f32]);
 	}
+
+	#[test]
+	fn test_from_roots() {
+		let poly = Polynomial::from_roots(&[1i64, 2, 3]);
+		assert_eq!(poly.coeffs(), coefficients![1i64, -6, 11, -6]);
+		assert!(Polynomial::<i64>::from_roots(&[]).is_one());
+		assert_eq!(
+			Polynomial::from_roots(&[2f32]).coeffs(),
+			coefficients![1f32, -2.0]
+		);
+
+		let roots: Vec<f64> = (0..20).map(|k| f64::from(k) / 4.0 - 2.0).collect();
+		let poly = Polynomial::from_roots(&roots);
+		assert_eq!(poly.order(), 20);
+		for &r in roots.iter() {
+			let y: f64 = poly.eval(r);
+			assert!(y.abs() < 1e-6);
+		}
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn test_par_mul() {
+		let a = Polynomial::new((0..1000).map(|k| f64::from(k).sin()).collect());
+		let b = Polynomial::new((0..700).map(|k| f64::from(k).cos()).collect());
+		let expected = &a * &b;
+		for &threads in [1, 2, 5].iter() {
+			let pool = rayon::ThreadPoolBuilder::new()
+				.num_threads(threads)
+				.build()
+				.unwrap();
+			assert_eq!(pool.install(|| a.par_mul(&b)), expected);
+			assert_eq!(pool.install(|| b.par_mul(&a)), &b * &a);
+		}
+
+		let a = Polynomial::new(coefficients![1f32, 3.0, 3.0, 0.0]);
+		assert!(a.par_mul(&Polynomial::zero()).is_zero());
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn test_par_from_roots() {
+		let roots: Vec<f64> = (0..300).map(|k| (f64::from(k) * 0.1).sin()).collect();
+		let expected = Polynomial::from_roots(&roots);
+		for &threads in [1, 3, 8].iter() {
+			let pool = rayon::ThreadPoolBuilder::new()
+				.num_threads(threads)
+				.build()
+				.unwrap();
+			assert_eq!(
+				pool.install(|| Polynomial::par_from_roots(&roots)),
+				expected
+			);
+		}
+	}
 }
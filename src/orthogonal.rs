@@ -0,0 +1,426 @@
+use crate::Polynomial;
+
+use core::ops::{Add, Div, Mul, Sub};
+use num::{FromPrimitive, One, Zero};
+use smallvec::{smallvec, SmallVec};
+
+// polynomials defined by p₋₁ = 0, p₀ = const. and pₖ₊₁(x) = (aₖx + bₖ)pₖ(x) - cₖpₖ₋₁(x), where
+// the coefficients function maps k to (aₖ, bₖ, cₖ)
+pub struct ThreeTermRecurrence<T, F = fn(usize) -> (T, T, T)> {
+	p0: T,
+	coefficients: F,
+}
+
+fn from_usize<T: FromPrimitive>(k: usize) -> T {
+	T::from_usize(k).unwrap()
+}
+
+impl<T, F: Fn(usize) -> (T, T, T)> ThreeTermRecurrence<T, F> {
+	pub fn new(p0: T, coefficients: F) -> Self {
+		Self { p0, coefficients }
+	}
+}
+
+// the classical families, whose coefficient functions are closures
+impl<T> ThreeTermRecurrence<T> {
+	// Pₖ with (k + 1)Pₖ₊₁ = (2k + 1)xPₖ - kPₖ₋₁
+	pub fn legendre() -> ThreeTermRecurrence<T, impl Fn(usize) -> (T, T, T)>
+	where
+		T: Zero + One + FromPrimitive + Div<Output = T>,
+	{
+		ThreeTermRecurrence::new(T::one(), |k| {
+			let k1: T = from_usize(k + 1);
+			(
+				from_usize::<T>(2 * k + 1) / k1,
+				T::zero(),
+				from_usize::<T>(k) / from_usize(k + 1),
+			)
+		})
+	}
+
+	// physicists' Hermite polynomials Hₖ with Hₖ₊₁ = 2xHₖ - 2kHₖ₋₁
+	pub fn hermite() -> ThreeTermRecurrence<T, impl Fn(usize) -> (T, T, T)>
+	where
+		T: Zero + One + FromPrimitive,
+	{
+		ThreeTermRecurrence::new(T::one(), |k| (from_usize(2), T::zero(), from_usize(2 * k)))
+	}
+
+	// probabilists' Hermite polynomials Heₖ with Heₖ₊₁ = xHeₖ - kHeₖ₋₁
+	pub fn hermite_probabilists() -> ThreeTermRecurrence<T, impl Fn(usize) -> (T, T, T)>
+	where
+		T: Zero + One + FromPrimitive,
+	{
+		ThreeTermRecurrence::new(T::one(), |k| (T::one(), T::zero(), from_usize(k)))
+	}
+
+	// generalized Laguerre polynomials Lₖ⁽ᵅ⁾ with (k + 1)Lₖ₊₁ = (2k + 1 + α - x)Lₖ - (k + α)Lₖ₋₁
+	pub fn laguerre(alpha: T) -> ThreeTermRecurrence<T, impl Fn(usize) -> (T, T, T)>
+	where
+		T: Zero + One + Clone + FromPrimitive + Add<Output = T> + Sub<Output = T> + Div<Output = T>,
+	{
+		ThreeTermRecurrence::new(T::one(), move |k| {
+			let k1: T = from_usize(k + 1);
+			(
+				T::zero() - T::one() / k1.clone(),
+				(from_usize::<T>(2 * k + 1) + alpha.clone()) / k1.clone(),
+				(from_usize::<T>(k) + alpha.clone()) / k1,
+			)
+		})
+	}
+
+	// Jacobi polynomials Pₖ⁽ᵅ·ᵝ⁾ with
+	// 2(k + 1)(k + α + β + 1)(2k + α + β)Pₖ₊₁ = (2k + α + β + 1)((2k + α + β + 2)(2k + α + β)x + α² - β²)Pₖ
+	//                                        - 2(k + α)(k + β)(2k + α + β + 2)Pₖ₋₁
+	// and P₁ = (α + β + 2)x / 2 + (α - β) / 2
+	pub fn jacobi(alpha: T, beta: T) -> ThreeTermRecurrence<T, impl Fn(usize) -> (T, T, T)>
+	where
+		T: Zero
+			+ One
+			+ Clone
+			+ FromPrimitive
+			+ Add<Output = T>
+			+ Sub<Output = T>
+			+ Mul<Output = T>
+			+ Div<Output = T>,
+	{
+		ThreeTermRecurrence::new(T::one(), move |k| {
+			let (a, b) = (alpha.clone(), beta.clone());
+			let two: T = from_usize(2);
+			if k == 0 {
+				return (
+					(a.clone() + b.clone() + two.clone()) / two.clone(),
+					(a - b) / two,
+					T::zero(),
+				);
+			}
+			let k: T = from_usize(k);
+			let s = two.clone() * k.clone() + a.clone() + b.clone();
+			let denominator = two.clone()
+				* (k.clone() + T::one())
+				* (k.clone() + a.clone() + b.clone() + T::one())
+				* s.clone();
+			let factor = s.clone() + T::one();
+			(
+				factor.clone() * (s.clone() + two.clone()) * s.clone() / denominator.clone(),
+				factor * (a.clone() * a.clone() - b.clone() * b.clone()) / denominator.clone(),
+				two.clone() * (k.clone() + a) * (k + b) * (s + two) / denominator,
+			)
+		})
+	}
+
+	// Gegenbauer polynomials Cₖ⁽λ⁾ with (k + 1)Cₖ₊₁ = 2(k + λ)xCₖ - (k + 2λ - 1)Cₖ₋₁
+	pub fn gegenbauer(lambda: T) -> ThreeTermRecurrence<T, impl Fn(usize) -> (T, T, T)>
+	where
+		T: Zero + One + Clone + FromPrimitive + Add<Output = T> + Sub<Output = T> + Div<Output = T>,
+	{
+		ThreeTermRecurrence::new(T::one(), move |k| {
+			let k1: T = from_usize(k + 1);
+			(
+				(from_usize::<T>(k) + lambda.clone() + from_usize::<T>(k) + lambda.clone())
+					/ k1.clone(),
+				T::zero(),
+				(from_usize::<T>(k) + lambda.clone() + lambda.clone() - T::one()) / k1,
+			)
+		})
+	}
+
+	// Chebyshev polynomials of the first kind Tₖ with T₁ = x and Tₖ₊₁ = 2xTₖ - Tₖ₋₁
+	pub fn chebyshev_t() -> ThreeTermRecurrence<T, impl Fn(usize) -> (T, T, T)>
+	where
+		T: Zero + One + FromPrimitive,
+	{
+		ThreeTermRecurrence::new(T::one(), |k| {
+			(from_usize(if k == 0 { 1 } else { 2 }), T::zero(), T::one())
+		})
+	}
+
+	// Chebyshev polynomials of the second kind Uₖ with U₁ = 2x and Uₖ₊₁ = 2xUₖ - Uₖ₋₁
+	pub fn chebyshev_u() -> ThreeTermRecurrence<T, impl Fn(usize) -> (T, T, T)>
+	where
+		T: Zero + One + FromPrimitive,
+	{
+		ThreeTermRecurrence::new(T::one(), |_| (from_usize(2), T::zero(), T::one()))
+	}
+
+	// Chebyshev polynomials of the third kind Vₖ with V₁ = 2x - 1 and Vₖ₊₁ = 2xVₖ - Vₖ₋₁
+	pub fn chebyshev_v() -> ThreeTermRecurrence<T, impl Fn(usize) -> (T, T, T)>
+	where
+		T: Zero + One + FromPrimitive + Sub<Output = T>,
+	{
+		ThreeTermRecurrence::new(T::one(), |k| {
+			let b = if k == 0 {
+				T::zero() - T::one()
+			} else {
+				T::zero()
+			};
+			(from_usize(2), b, T::one())
+		})
+	}
+
+	// Chebyshev polynomials of the fourth kind Wₖ with W₁ = 2x + 1 and Wₖ₊₁ = 2xWₖ - Wₖ₋₁
+	pub fn chebyshev_w() -> ThreeTermRecurrence<T, impl Fn(usize) -> (T, T, T)>
+	where
+		T: Zero + One + FromPrimitive,
+	{
+		ThreeTermRecurrence::new(T::one(), |k| {
+			let b = if k == 0 { T::one() } else { T::zero() };
+			(from_usize(2), b, T::one())
+		})
+	}
+}
+
+impl<T, F: Fn(usize) -> (T, T, T)> ThreeTermRecurrence<T, F> {
+	// the coefficients of pₙ
+	pub fn polynomial(&self, n: usize) -> Polynomial<T>
+	where
+		T: Zero + Clone + Sub<Output = T> + Mul<Output = T>,
+	{
+		let mut previous: SmallVec<[T; 8]> = SmallVec::new();
+		let mut current: SmallVec<[T; 8]> = smallvec![self.p0.clone()];
+		for k in 0..n {
+			let (a, b, c) = (self.coefficients)(k);
+			// (aₖx + bₖ)pₖ - cₖpₖ₋₁
+			let mut next: SmallVec<[T; 8]> = (0..k + 2).map(|_| T::zero()).collect();
+			for (i, p) in current.iter().enumerate() {
+				next[i + 1] = next[i + 1].clone() + a.clone() * p.clone();
+				next[i] = next[i].clone() + b.clone() * p.clone();
+			}
+			for (i, p) in previous.iter().enumerate() {
+				next[i] = next[i].clone() - c.clone() * p.clone();
+			}
+			previous = core::mem::replace(&mut current, next);
+		}
+		Polynomial::new_reversed(current)
+	}
+
+	// pₙ(x) using the recurrence, which is more stable than evaluating the coefficients for large n
+	pub fn eval(&self, n: usize, x: &T) -> T
+	where
+		T: Zero + Clone + Sub<Output = T> + Mul<Output = T>,
+	{
+		let mut previous = T::zero();
+		let mut current = self.p0.clone();
+		for k in 0..n {
+			let (a, b, c) = (self.coefficients)(k);
+			let next = (a * x.clone() + b) * current.clone() - c * previous;
+			previous = core::mem::replace(&mut current, next);
+		}
+		current
+	}
+
+	// Σ cₖpₖ(x) using Clenshaw's algorithm:
+	// βₖ = cₖ + (aₖx + bₖ)βₖ₊₁ - cₖ₊₁βₖ₊₂ and the sum is p₀β₀
+	pub fn eval_series(&self, coeffs: &[T], x: &T) -> T
+	where
+		T: Zero + Clone + Sub<Output = T> + Mul<Output = T>,
+	{
+		let (mut b1, mut b2) = (T::zero(), T::zero());
+		let mut c_next = T::zero();
+		for (k, coeff) in coeffs.iter().enumerate().rev() {
+			let (a, b, c) = (self.coefficients)(k);
+			let v = coeff.clone() + (a * x.clone() + b) * b1.clone() - c_next * b2;
+			b2 = core::mem::replace(&mut b1, v);
+			c_next = c;
+		}
+		self.p0.clone() * b1
+	}
+}
+
+impl<T> Polynomial<T> {
+	pub fn legendre(n: usize) -> Self
+	where
+		T: Zero + One + Clone + FromPrimitive + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+	{
+		ThreeTermRecurrence::legendre().polynomial(n)
+	}
+
+	pub fn hermite(n: usize) -> Self
+	where
+		T: Zero + One + Clone + FromPrimitive + Sub<Output = T> + Mul<Output = T>,
+	{
+		ThreeTermRecurrence::hermite().polynomial(n)
+	}
+
+	pub fn hermite_probabilists(n: usize) -> Self
+	where
+		T: Zero + One + Clone + FromPrimitive + Sub<Output = T> + Mul<Output = T>,
+	{
+		ThreeTermRecurrence::hermite_probabilists().polynomial(n)
+	}
+
+	pub fn laguerre(n: usize, alpha: T) -> Self
+	where
+		T: Zero + One + Clone + FromPrimitive + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+	{
+		ThreeTermRecurrence::laguerre(alpha).polynomial(n)
+	}
+
+	pub fn jacobi(n: usize, alpha: T, beta: T) -> Self
+	where
+		T: Zero + One + Clone + FromPrimitive + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+	{
+		ThreeTermRecurrence::jacobi(alpha, beta).polynomial(n)
+	}
+
+	pub fn gegenbauer(n: usize, lambda: T) -> Self
+	where
+		T: Zero + One + Clone + FromPrimitive + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+	{
+		ThreeTermRecurrence::gegenbauer(lambda).polynomial(n)
+	}
+
+	pub fn chebyshev_t(n: usize) -> Self
+	where
+		T: Zero + One + Clone + FromPrimitive + Sub<Output = T> + Mul<Output = T>,
+	{
+		ThreeTermRecurrence::chebyshev_t().polynomial(n)
+	}
+
+	pub fn chebyshev_u(n: usize) -> Self
+	where
+		T: Zero + One + Clone + FromPrimitive + Sub<Output = T> + Mul<Output = T>,
+	{
+		ThreeTermRecurrence::chebyshev_u().polynomial(n)
+	}
+
+	pub fn chebyshev_v(n: usize) -> Self
+	where
+		T: Zero + One + Clone + FromPrimitive + Sub<Output = T> + Mul<Output = T>,
+	{
+		ThreeTermRecurrence::chebyshev_v().polynomial(n)
+	}
+
+	pub fn chebyshev_w(n: usize) -> Self
+	where
+		T: Zero + One + Clone + FromPrimitive + Sub<Output = T> + Mul<Output = T>,
+	{
+		ThreeTermRecurrence::chebyshev_w().polynomial(n)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use num::BigRational;
+
+	fn r(n: i64, d: i64) -> BigRational {
+		BigRational::new(n.into(), d.into())
+	}
+
+	fn rs(coeffs: &[(i64, i64)]) -> Polynomial<BigRational> {
+		Polynomial::new(coeffs.iter().map(|&(n, d)| r(n, d)).collect())
+	}
+
+	#[test]
+	fn test_legendre() {
+		assert_eq!(Polynomial::legendre(0), rs(&[(1, 1)]));
+		// (5x³ - 3x) / 2
+		assert_eq!(
+			Polynomial::legendre(3),
+			rs(&[(5, 2), (0, 1), (-3, 2), (0, 1)])
+		);
+		// (35x⁴ - 30x² + 3) / 8
+		assert_eq!(
+			Polynomial::legendre(4),
+			rs(&[(35, 8), (0, 1), (-30, 8), (0, 1), (3, 8)])
+		);
+		let p20: Polynomial<BigRational> = Polynomial::legendre(20);
+		assert_eq!(p20.eval(r(1, 1)), r(1, 1));
+		assert_eq!(p20.eval(r(-1, 1)), r(1, 1));
+	}
+
+	#[test]
+	fn test_hermite_laguerre() {
+		assert_eq!(
+			Polynomial::<i64>::hermite(3).coeffs(),
+			coefficients![8, 0, -12, 0]
+		);
+		assert_eq!(
+			Polynomial::<i64>::hermite_probabilists(4).coeffs(),
+			coefficients![1, 0, -6, 0, 3]
+		);
+		// L₂⁽ᵅ⁾ = x² / 2 - (α + 2)x + (α + 1)(α + 2) / 2 with α = 1/2
+		assert_eq!(
+			Polynomial::laguerre(2, r(1, 2)),
+			rs(&[(1, 2), (-5, 2), (15, 8)])
+		);
+		assert_eq!(
+			Polynomial::laguerre(3, r(0, 1)),
+			rs(&[(-1, 6), (3, 2), (-3, 1), (1, 1)])
+		);
+	}
+
+	#[test]
+	fn test_jacobi_gegenbauer_chebyshev() {
+		for n in 0..8 {
+			// special cases
+			let legendre: Polynomial<BigRational> = Polynomial::legendre(n);
+			assert_eq!(Polynomial::jacobi(n, r(0, 1), r(0, 1)), legendre);
+			assert_eq!(Polynomial::gegenbauer(n, r(1, 2)), legendre);
+			assert_eq!(
+				Polynomial::gegenbauer(n, r(1, 1)),
+				Polynomial::chebyshev_u(n)
+			);
+			// Tₙ = Pₙ⁽⁻¹ᐟ²·⁻¹ᐟ²⁾ / Pₙ⁽⁻¹ᐟ²·⁻¹ᐟ²⁾(1)
+			let jacobi = Polynomial::jacobi(n, r(-1, 2), r(-1, 2));
+			let scale = jacobi.eval(r(1, 1));
+			assert_eq!(jacobi * (r(1, 1) / scale), Polynomial::chebyshev_t(n));
+		}
+
+		// the first kind matches the Chebyshev basis
+		let mut coeffs = coefficients![0i64; 6];
+		coeffs[5] = 1;
+		assert_eq!(
+			ChebyshevPolynomial::new(coeffs).to_polynomial(),
+			Polynomial::chebyshev_t(5)
+		);
+		assert_eq!(
+			Polynomial::<i64>::chebyshev_v(2).coeffs(),
+			coefficients![4, -2, -1]
+		);
+		assert_eq!(
+			Polynomial::<i64>::chebyshev_w(2).coeffs(),
+			coefficients![4, 2, -1]
+		);
+	}
+
+	#[test]
+	fn test_three_term_recurrence() {
+		let legendre = ThreeTermRecurrence::legendre();
+		let p = legendre.polynomial(6);
+		for k in -3..=3 {
+			let x = r(k, 4);
+			assert_eq!(legendre.eval(6, &x), p.eval(x));
+		}
+
+		// Σ cₖPₖ(x)
+		let coeffs = [r(1, 1), r(-2, 3), r(0, 1), r(5, 2)];
+		let mut sum = Polynomial::zero();
+		for (k, c) in coeffs.iter().enumerate() {
+			sum += legendre.polynomial(k) * c.clone();
+		}
+		for k in -3..=3 {
+			let x = r(k, 5);
+			assert_eq!(legendre.eval_series(&coeffs, &x), sum.eval(x));
+		}
+
+		// the recurrence remains accurate where the coefficients of P₆₀ do not
+		let legendre = ThreeTermRecurrence::<f64>::legendre();
+		let x = 0.3f64;
+		let (mut p0, mut p1) = (1.0, x);
+		for k in 1..60 {
+			let p2 = ((2 * k + 1) as f64 * x * p1 - k as f64 * p0) / (k + 1) as f64;
+			p0 = p1;
+			p1 = p2;
+		}
+		assert!((legendre.eval(60, &x) - p1).abs() < 1e-14);
+		let hermite = ThreeTermRecurrence::hermite();
+		assert_eq!(hermite.eval_series(&[1.0, 0.0, 1.0], &2.0), 15.0);
+
+		// coefficients borrowed from the caller: pₖ₊₁ = sₖxpₖ - pₖ₋₁
+		let scales = [r(1, 1), r(2, 1), r(3, 1)];
+		let custom = ThreeTermRecurrence::new(r(1, 1), |k| (scales[k].clone(), r(0, 1), r(1, 1)));
+		assert_eq!(custom.polynomial(2), rs(&[(2, 1), (0, 1), (-1, 1)]));
+	}
+}
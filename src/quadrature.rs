@@ -0,0 +1,278 @@
+use crate::linalg::symmetric_tridiagonal_eigen;
+use crate::ThreeTermRecurrence;
+
+// nodes xᵢ and weights wᵢ such that Σ wᵢf(xᵢ) approximates ∫ f(x)w(x) dx for the weight function w
+// of the rule, integrating polynomials up to a certain order exactly
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuadratureRule {
+	pub nodes: Vec<f64>,
+	pub weights: Vec<f64>,
+}
+
+// ln Γ(x) for x > 0 using the Lanczos approximation with g = 7
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+	const COEFFS: [f64; 9] = [
+		0.999_999_999_999_809_9,
+		676.520_368_121_885_1,
+		-1_259.139_216_722_402_8,
+		771.323_428_777_653_1,
+		-176.615_029_162_140_6,
+		12.507_343_278_686_905,
+		-0.138_571_095_265_720_12,
+		9.984_369_578_019_572e-6,
+		1.505_632_735_149_311_6e-7,
+	];
+	assert!(x > 0.0);
+	if x < 0.5 {
+		// reflection formula
+		return (core::f64::consts::PI / (core::f64::consts::PI * x).sin()).ln()
+			- ln_gamma(1.0 - x);
+	}
+	let x = x - 1.0;
+	let sum = COEFFS
+		.iter()
+		.enumerate()
+		.skip(1)
+		.fold(COEFFS[0], |sum, (k, c)| sum + c / (x + k as f64));
+	let t = x + 7.5;
+	0.5 * (2.0 * core::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+impl QuadratureRule {
+	// Golub-Welsch algorithm: for orthonormal polynomials with the monic recurrence
+	// pₖ₊₁(x) = (x - αₖ)pₖ(x) - βₖpₖ₋₁(x), the nodes are the eigenvalues of the Jacobi matrix with
+	// diagonal αₖ and off-diagonal √βₖ, and the weights are μ₀ = ∫ w(x) dx times the squared first
+	// components of the normalized eigenvectors
+	fn golub_welsch(alphas: &[f64], betas: &[f64], mu0: f64) -> Option<Self> {
+		let off_diagonal: Vec<f64> = betas.iter().map(|b| b.sqrt()).collect();
+		let (nodes, first) = symmetric_tridiagonal_eigen(alphas, &off_diagonal)?;
+		let mut pairs: Vec<(f64, f64)> = nodes
+			.into_iter()
+			.zip(first.into_iter().map(|z| mu0 * z * z))
+			.collect();
+		pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+		let (nodes, weights) = pairs.into_iter().unzip();
+		Some(Self { nodes, weights })
+	}
+
+	// w(x) = 1 on [-1, 1], exact for polynomials of order up to 2n - 1
+	pub fn gauss_legendre(n: usize) -> Option<Self> {
+		assert!(n >= 1);
+		let betas: Vec<f64> = (1..n)
+			.map(|k| {
+				let k = (k * k) as f64;
+				k / (4.0 * k - 1.0)
+			})
+			.collect();
+		Self::golub_welsch(&vec![0.0; n], &betas, 2.0)
+	}
+
+	// w(x) = exp(-x²) on (-∞, ∞), exact for polynomials of order up to 2n - 1
+	pub fn gauss_hermite(n: usize) -> Option<Self> {
+		assert!(n >= 1);
+		let betas: Vec<f64> = (1..n).map(|k| k as f64 / 2.0).collect();
+		Self::golub_welsch(&vec![0.0; n], &betas, core::f64::consts::PI.sqrt())
+	}
+
+	// w(x) = xᵅexp(-x) on [0, ∞) with α > -1, exact for polynomials of order up to 2n - 1
+	pub fn gauss_laguerre(n: usize, alpha: f64) -> Option<Self> {
+		assert!(n >= 1 && alpha > -1.0);
+		let alphas: Vec<f64> = (0..n).map(|k| (2 * k + 1) as f64 + alpha).collect();
+		let betas: Vec<f64> = (1..n).map(|k| k as f64 * (k as f64 + alpha)).collect();
+		Self::golub_welsch(&alphas, &betas, ln_gamma(alpha + 1.0).exp())
+	}
+
+	// w(x) = (1 - x)ᵅ(1 + x)ᵝ on [-1, 1] with α, β > -1, exact for polynomials of order up to 2n - 1
+	pub fn gauss_jacobi(n: usize, alpha: f64, beta: f64) -> Option<Self> {
+		assert!(n >= 1 && alpha > -1.0 && beta > -1.0);
+		let (a, b) = (alpha, beta);
+		let alphas: Vec<f64> = (0..n)
+			.map(|k| {
+				let s = (2 * k) as f64 + a + b;
+				if k == 0 {
+					(b - a) / (s + 2.0)
+				} else {
+					(b * b - a * a) / (s * (s + 2.0))
+				}
+			})
+			.collect();
+		let betas: Vec<f64> = (1..n)
+			.map(|k| {
+				let k = k as f64;
+				let s = 2.0 * k + a + b;
+				if k == 1.0 {
+					// the factor (1 + α + β) cancels, which matters for α + β = -1
+					4.0 * (1.0 + a) * (1.0 + b) / (s * s * (s + 1.0))
+				} else {
+					4.0 * k * (k + a) * (k + b) * (k + a + b) / (s * s * (s + 1.0) * (s - 1.0))
+				}
+			})
+			.collect();
+		let mu0 = ((a + b + 1.0) * 2f64.ln() + ln_gamma(a + 1.0) + ln_gamma(b + 1.0)
+			- ln_gamma(a + b + 2.0))
+		.exp();
+		Self::golub_welsch(&alphas, &betas, mu0)
+	}
+
+	// w(x) = 1 on [-1, 1] with both endpoints as nodes, exact for polynomials of order up to 2n - 3
+	// the interior nodes are those of the Gauss-Jacobi rule with α = β = 1, and the weights are
+	// 2 / (n(n - 1)Pₙ₋₁(xᵢ)²)
+	pub fn gauss_lobatto(n: usize) -> Option<Self> {
+		assert!(n >= 2);
+		let interior = if n > 2 {
+			Self::gauss_jacobi(n - 2, 1.0, 1.0)?.nodes
+		} else {
+			vec![]
+		};
+		let legendre = ThreeTermRecurrence::<f64>::legendre();
+		let scale = (n * (n - 1)) as f64;
+		let nodes: Vec<f64> = core::iter::once(-1.0)
+			.chain(interior)
+			.chain(core::iter::once(1.0))
+			.collect();
+		let weights = nodes
+			.iter()
+			.map(|x| {
+				let p = legendre.eval(n - 1, x);
+				2.0 / (scale * p * p)
+			})
+			.collect();
+		Some(Self { nodes, weights })
+	}
+
+	// w(x) = 1 on [-1, 1] with -1 as a node, exact for polynomials of order up to 2n - 2
+	// the other nodes are those of the Gauss-Jacobi rule with α = 0 and β = 1, and the weights are
+	// (1 - xᵢ) / (n²Pₙ₋₁(xᵢ)²)
+	pub fn gauss_radau(n: usize) -> Option<Self> {
+		assert!(n >= 1);
+		let others = if n > 1 {
+			Self::gauss_jacobi(n - 1, 0.0, 1.0)?.nodes
+		} else {
+			vec![]
+		};
+		let legendre = ThreeTermRecurrence::<f64>::legendre();
+		let scale = (n * n) as f64;
+		let nodes: Vec<f64> = core::iter::once(-1.0).chain(others).collect();
+		let weights = nodes
+			.iter()
+			.map(|x| {
+				let p = legendre.eval(n - 1, x);
+				(1.0 - x) / (scale * p * p)
+			})
+			.collect();
+		Some(Self { nodes, weights })
+	}
+
+	pub fn integrate<F: Fn(f64) -> f64>(&self, f: F) -> f64 {
+		self.nodes
+			.iter()
+			.zip(self.weights.iter())
+			.map(|(&x, w)| w * f(x))
+			.sum()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ln_gamma;
+	use crate::*;
+
+	// polynomial of order k with coefficients of both signs
+	fn test_polynomial(k: usize) -> Polynomial<f64> {
+		Polynomial::new((0..=k).map(|i| (i % 3) as f64 - 0.75).collect())
+	}
+
+	fn assert_close(a: f64, b: f64) {
+		assert!((a - b).abs() <= 1e-12 * b.abs().max(1.0), "{} != {}", a, b);
+	}
+
+	#[test]
+	fn test_ln_gamma() {
+		assert_close(ln_gamma(1.0), 0.0);
+		assert_close(ln_gamma(5.0), 24f64.ln());
+		assert_close(ln_gamma(0.5), core::f64::consts::PI.sqrt().ln());
+		assert_close(ln_gamma(0.25), 3.625_609_908_221_908f64.ln());
+		assert_close(ln_gamma(101.0), 363.739_375_555_563_5);
+	}
+
+	#[test]
+	fn test_gauss_legendre_jacobi() {
+		for n in 1..=12 {
+			let rule = QuadratureRule::gauss_legendre(n).unwrap();
+			let p = test_polynomial(2 * n - 1);
+			let exact = p.definite_integral(-1.0, 1.0).unwrap();
+			assert_close(rule.integrate(|x| p.eval(x)), exact);
+			assert!(rule.nodes.windows(2).all(|w| w[0] < w[1]));
+
+			// w(x) = (1 - x)²(1 + x)
+			let rule = QuadratureRule::gauss_jacobi(n, 2.0, 1.0).unwrap();
+			let weight = Polynomial::new(coefficients![1.0, -1.0, -1.0, 1.0]);
+			let exact = (&p * &weight).definite_integral(-1.0, 1.0).unwrap();
+			assert_close(rule.integrate(|x| p.eval(x)), exact);
+		}
+
+		// α = β = -1/2 is the Gauss-Chebyshev rule with nodes cos((2k - 1)π / 2n) and weights π / n
+		let n = 7;
+		let rule = QuadratureRule::gauss_jacobi(n, -0.5, -0.5).unwrap();
+		for (k, (x, w)) in rule.nodes.iter().rev().zip(rule.weights.iter()).enumerate() {
+			let angle = (2 * k + 1) as f64 * core::f64::consts::PI / (2 * n) as f64;
+			assert!((x - angle.cos()).abs() < 1e-14);
+			assert_close(*w, core::f64::consts::PI / n as f64);
+		}
+	}
+
+	#[test]
+	fn test_gauss_hermite_laguerre() {
+		for n in 1..=10 {
+			// ∫ x²ᵏexp(-x²) dx = √π (2k - 1)!! / 2ᵏ
+			let p = test_polynomial(2 * n - 1);
+			let mut moment = core::f64::consts::PI.sqrt();
+			let mut exact = 0.0;
+			for (k, c) in p.coeffs().iter().rev().enumerate() {
+				if k % 2 == 0 {
+					exact += c * moment;
+					moment *= (k + 1) as f64 / 2.0;
+				}
+			}
+			let rule = QuadratureRule::gauss_hermite(n).unwrap();
+			assert_close(rule.integrate(|x| p.eval(x)), exact);
+
+			// ∫ p(x)exp(-x) dx over [0, ∞) is the sum of all derivatives of p at 0
+			let exact: f64 = p.eval_all_derivatives(0.0, 2 * n - 1).unwrap().iter().sum();
+			let rule = QuadratureRule::gauss_laguerre(n, 0.0).unwrap();
+			assert_close(rule.integrate(|x| p.eval(x)), exact);
+		}
+
+		// ∫ x³x^½exp(-x) dx = Γ(9/2) = 105√π / 16
+		let rule = QuadratureRule::gauss_laguerre(2, 0.5).unwrap();
+		assert_close(
+			rule.integrate(|x| x * x * x),
+			105.0 * core::f64::consts::PI.sqrt() / 16.0,
+		);
+	}
+
+	#[test]
+	fn test_gauss_lobatto_radau() {
+		for n in 2..=10 {
+			let rule = QuadratureRule::gauss_lobatto(n).unwrap();
+			assert_eq!(rule.nodes.len(), n);
+			assert_eq!((rule.nodes[0], rule.nodes[n - 1]), (-1.0, 1.0));
+			let p = test_polynomial(2 * n - 3);
+			let exact = p.definite_integral(-1.0, 1.0).unwrap();
+			assert_close(rule.integrate(|x| p.eval(x)), exact);
+		}
+		for n in 1..=10 {
+			let rule = QuadratureRule::gauss_radau(n).unwrap();
+			assert_eq!(rule.nodes.len(), n);
+			assert_eq!(rule.nodes[0], -1.0);
+			let p = test_polynomial(2 * n - 2);
+			let exact = p.definite_integral(-1.0, 1.0).unwrap();
+			assert_close(rule.integrate(|x| p.eval(x)), exact);
+		}
+
+		// Simpson's rule
+		let rule = QuadratureRule::gauss_lobatto(3).unwrap();
+		assert_close(rule.weights[0], 1.0 / 3.0);
+		assert_close(rule.weights[1], 4.0 / 3.0);
+	}
+}
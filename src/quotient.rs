@@ -0,0 +1,425 @@
+use crate::{ModInt, Polynomial};
+
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use num::{One, Zero};
+use smallvec::smallvec;
+use std::fmt::{Debug, Formatter};
+
+pub trait Modulus<T> {
+	fn modulus() -> Polynomial<T>;
+}
+
+// residue classes of polynomials modulo M::modulus()
+// if the modulus is irreducible, this is a field, e.g., GF(pᵏ) for polynomials over GF(p)
+pub struct QuotientRing<T, M> {
+	value: Polynomial<T>,
+	modulus: PhantomData<fn() -> M>,
+}
+
+pub type ExtensionField<T, M> = QuotientRing<T, M>;
+
+// x⁸ + x⁴ + x³ + x² + 1, for which x is a primitive element (as used by most Reed–Solomon codes)
+pub struct Gf256Modulus;
+// x⁸ + x⁴ + x³ + x + 1, as used by AES (x + 1 is a primitive element)
+pub struct Gf256AesModulus;
+// x¹⁶ + x¹² + x³ + x + 1, for which x is a primitive element
+pub struct Gf65536Modulus;
+
+impl Modulus<ModInt<2>> for Gf256Modulus {
+	fn modulus() -> Polynomial<ModInt<2>> {
+		Polynomial::from_bits(0x11d)
+	}
+}
+
+impl Modulus<ModInt<2>> for Gf256AesModulus {
+	fn modulus() -> Polynomial<ModInt<2>> {
+		Polynomial::from_bits(0x11b)
+	}
+}
+
+impl Modulus<ModInt<2>> for Gf65536Modulus {
+	fn modulus() -> Polynomial<ModInt<2>> {
+		Polynomial::from_bits(0x1100b)
+	}
+}
+
+pub type Gf256 = ExtensionField<ModInt<2>, Gf256Modulus>;
+pub type Gf256Aes = ExtensionField<ModInt<2>, Gf256AesModulus>;
+pub type Gf65536 = ExtensionField<ModInt<2>, Gf65536Modulus>;
+
+impl<T, M> QuotientRing<T, M>
+where
+	M: Modulus<T>,
+{
+	pub fn new(value: Polynomial<T>) -> Self
+	where
+		T: Zero + Clone + for<'r> AddAssign<&'r T> + SubAssign,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let (_, value) = value.div_rem(&M::modulus());
+		Self {
+			value,
+			modulus: PhantomData,
+		}
+	}
+
+	pub fn value(&self) -> &Polynomial<T> {
+		&self.value
+	}
+
+	pub fn into_value(self) -> Polynomial<T> {
+		self.value
+	}
+
+	// returns None if the value is not invertible, i.e., not coprime to the modulus
+	pub fn inv(&self) -> Option<Self>
+	where
+		T: Zero
+			+ One
+			+ Clone
+			+ PartialEq
+			+ AddAssign
+			+ for<'r> AddAssign<&'r T>
+			+ SubAssign
+			+ for<'r> SubAssign<&'r T>,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let (g, s, _) = self.value.extended_gcd(&M::modulus());
+		if g.is_one() {
+			Some(Self::new(s))
+		} else {
+			None
+		}
+	}
+
+	pub fn pow(&self, exp: u64) -> Self
+	where
+		T: Zero + One + Clone + AddAssign + for<'r> AddAssign<&'r T> + SubAssign,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let mut base = self.clone();
+		let mut exp = exp;
+		let mut ret = Self::one();
+		while exp > 0 {
+			if exp & 1 == 1 {
+				ret *= &base;
+			}
+			base = &base * &base;
+			exp >>= 1;
+		}
+		ret
+	}
+}
+
+// binary extension fields are commonly written as bit patterns, e.g., bytes for GF(2⁸)
+impl<M> QuotientRing<ModInt<2>, M>
+where
+	M: Modulus<ModInt<2>>,
+{
+	pub fn from_bits(bits: u64) -> Self {
+		Self::new(Polynomial::from_bits(bits))
+	}
+
+	pub fn to_bits(&self) -> u64 {
+		self.value.to_bits()
+	}
+}
+
+impl<T: Clone, M> Clone for QuotientRing<T, M> {
+	fn clone(&self) -> Self {
+		Self {
+			value: self.value.clone(),
+			modulus: PhantomData,
+		}
+	}
+}
+
+impl<T: PartialEq, M> PartialEq for QuotientRing<T, M> {
+	fn eq(&self, rhs: &Self) -> bool {
+		self.value == rhs.value
+	}
+}
+
+impl<T: Eq, M> Eq for QuotientRing<T, M> {}
+
+impl<T: Debug, M> Debug for QuotientRing<T, M> {
+	fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+		f.debug_tuple("QuotientRing").field(&self.value).finish()
+	}
+}
+
+impl<T, M> AddAssign<&QuotientRing<T, M>> for QuotientRing<T, M>
+where
+	T: for<'r> AddAssign<&'r T> + Zero,
+{
+	fn add_assign(&mut self, rhs: &QuotientRing<T, M>) {
+		self.value += &rhs.value;
+	}
+}
+
+impl<T, M> SubAssign<&QuotientRing<T, M>> for QuotientRing<T, M>
+where
+	T: for<'r> SubAssign<&'r T> + Zero,
+{
+	fn sub_assign(&mut self, rhs: &QuotientRing<T, M>) {
+		self.value -= &rhs.value;
+	}
+}
+
+impl<T, M> MulAssign<&QuotientRing<T, M>> for QuotientRing<T, M>
+where
+	T: Zero + Clone + AddAssign + for<'r> AddAssign<&'r T> + SubAssign,
+	for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	M: Modulus<T>,
+{
+	fn mul_assign(&mut self, rhs: &QuotientRing<T, M>) {
+		*self = Self::new(&self.value * &rhs.value);
+	}
+}
+
+impl<T, M> DivAssign<&QuotientRing<T, M>> for QuotientRing<T, M>
+where
+	T: Zero
+		+ One
+		+ Clone
+		+ PartialEq
+		+ AddAssign
+		+ for<'r> AddAssign<&'r T>
+		+ SubAssign
+		+ for<'r> SubAssign<&'r T>,
+	for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	M: Modulus<T>,
+{
+	// division is multiplication by the inverse, which causes clippy to emit a spurious warning
+	#[allow(clippy::suspicious_op_assign_impl)]
+	fn div_assign(&mut self, rhs: &QuotientRing<T, M>) {
+		let inv = rhs.inv().expect("divisor is not invertible");
+		*self *= &inv;
+	}
+}
+
+macro_rules! forward_binary_op {
+	($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $($bounds:tt)*) => {
+		impl<T, M> $OpAssign<QuotientRing<T, M>> for QuotientRing<T, M>
+		where
+			$($bounds)*
+		{
+			fn $op_assign(&mut self, rhs: QuotientRing<T, M>) {
+				self.$op_assign(&rhs);
+			}
+		}
+
+		impl<T, M> $Op<QuotientRing<T, M>> for QuotientRing<T, M>
+		where
+			$($bounds)*
+		{
+			type Output = QuotientRing<T, M>;
+			fn $op(self, rhs: QuotientRing<T, M>) -> QuotientRing<T, M> {
+				let mut ret = self;
+				ret.$op_assign(&rhs);
+				ret
+			}
+		}
+
+		impl<T, M> $Op<&QuotientRing<T, M>> for QuotientRing<T, M>
+		where
+			$($bounds)*
+		{
+			type Output = QuotientRing<T, M>;
+			fn $op(self, rhs: &QuotientRing<T, M>) -> QuotientRing<T, M> {
+				let mut ret = self;
+				ret.$op_assign(rhs);
+				ret
+			}
+		}
+
+		impl<T, M> $Op<QuotientRing<T, M>> for &QuotientRing<T, M>
+		where
+			T: Clone,
+			$($bounds)*
+		{
+			type Output = QuotientRing<T, M>;
+			fn $op(self, rhs: QuotientRing<T, M>) -> QuotientRing<T, M> {
+				let mut ret = self.clone();
+				ret.$op_assign(&rhs);
+				ret
+			}
+		}
+
+		impl<T, M> $Op<&QuotientRing<T, M>> for &QuotientRing<T, M>
+		where
+			T: Clone,
+			$($bounds)*
+		{
+			type Output = QuotientRing<T, M>;
+			fn $op(self, rhs: &QuotientRing<T, M>) -> QuotientRing<T, M> {
+				let mut ret = self.clone();
+				ret.$op_assign(rhs);
+				ret
+			}
+		}
+	};
+}
+
+forward_binary_op!(Add, add, AddAssign, add_assign, T: for<'r> AddAssign<&'r T> + Zero,);
+forward_binary_op!(Sub, sub, SubAssign, sub_assign, T: for<'r> SubAssign<&'r T> + Zero,);
+forward_binary_op!(
+	Mul,
+	mul,
+	MulAssign,
+	mul_assign,
+	T: Zero + Clone + AddAssign + for<'r> AddAssign<&'r T> + SubAssign,
+	for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	M: Modulus<T>,
+);
+forward_binary_op!(
+	Div,
+	div,
+	DivAssign,
+	div_assign,
+	T: Zero
+		+ One
+		+ Clone
+		+ PartialEq
+		+ AddAssign
+		+ for<'r> AddAssign<&'r T>
+		+ SubAssign
+		+ for<'r> SubAssign<&'r T>,
+	for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	M: Modulus<T>,
+);
+
+impl<T, M> Neg for QuotientRing<T, M>
+where
+	T: Zero,
+	for<'l> &'l T: Neg<Output = T>,
+{
+	type Output = QuotientRing<T, M>;
+	fn neg(self) -> QuotientRing<T, M> {
+		Self {
+			value: -self.value,
+			modulus: PhantomData,
+		}
+	}
+}
+
+impl<T, M> Neg for &QuotientRing<T, M>
+where
+	T: Zero,
+	for<'l> &'l T: Neg<Output = T>,
+{
+	type Output = QuotientRing<T, M>;
+	fn neg(self) -> QuotientRing<T, M> {
+		QuotientRing {
+			value: -&self.value,
+			modulus: PhantomData,
+		}
+	}
+}
+
+impl<T, M> Zero for QuotientRing<T, M>
+where
+	T: for<'r> AddAssign<&'r T> + Zero,
+{
+	fn zero() -> Self {
+		Self {
+			value: Polynomial::zero(),
+			modulus: PhantomData,
+		}
+	}
+
+	fn is_zero(&self) -> bool {
+		self.value.is_zero()
+	}
+}
+
+impl<T, M> One for QuotientRing<T, M>
+where
+	T: Zero + One + Clone + AddAssign + for<'r> AddAssign<&'r T> + SubAssign,
+	for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	M: Modulus<T>,
+{
+	fn one() -> Self {
+		// the modulus may be constant, in which case every residue is zero
+		Self::new(Polynomial::new_reversed(smallvec![T::one()]))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use num::rational::Rational64;
+	use num::{One, Zero};
+
+	#[test]
+	fn test_gf256_aes() {
+		// examples from FIPS 197
+		let a = Gf256Aes::from_bits(0x57);
+		let b = Gf256Aes::from_bits(0x83);
+		assert_eq!((&a + &b).to_bits(), 0xd4);
+		assert_eq!((&a * &b).to_bits(), 0xc1);
+		assert_eq!((&a * &Gf256Aes::from_bits(0x13)).to_bits(), 0xfe);
+		assert_eq!(Gf256Aes::from_bits(0x53).inv().unwrap().to_bits(), 0xca);
+		assert_eq!((&a / &b * &b), a);
+	}
+
+	#[test]
+	fn test_gf256_inverse() {
+		assert!(Gf256::zero().inv().is_none());
+		for bits in 1..256 {
+			let a = Gf256::from_bits(bits);
+			let inv = a.inv().unwrap();
+			assert!((&a * &inv).is_one());
+			assert_eq!(a.pow(254), inv);
+		}
+	}
+
+	#[test]
+	fn test_primitive_elements() {
+		let x = Gf256::from_bits(2);
+		assert!(x.pow(255).is_one());
+		for d in [3, 5, 17].iter() {
+			assert!(!x.pow(255 / d).is_one());
+		}
+
+		let x = Gf65536::from_bits(2);
+		assert!(x.pow(65535).is_one());
+		for d in [3, 5, 17, 257].iter() {
+			assert!(!x.pow(65535 / d).is_one());
+		}
+	}
+
+	struct ComplexModulus;
+
+	impl Modulus<Rational64> for ComplexModulus {
+		fn modulus() -> Polynomial<Rational64> {
+			// x² + 1
+			Polynomial::new(coefficients![
+				Rational64::one(),
+				Rational64::zero(),
+				Rational64::one()
+			])
+		}
+	}
+
+	#[test]
+	fn test_quotient_ring_rational() {
+		type C = QuotientRing<Rational64, ComplexModulus>;
+		let r = |v| Rational64::from_integer(v);
+		// (1 + 2i)(3 - i) = 5 + 5i
+		let a = C::new(Polynomial::new(coefficients![r(2), r(1)]));
+		let b = C::new(Polynomial::new(coefficients![r(-1), r(3)]));
+		assert_eq!((&a * &b).value().coeffs(), coefficients![r(5), r(5)]);
+		// 1 / i = -i
+		let i = C::new(Polynomial::new(coefficients![r(1), r(0)]));
+		assert_eq!(
+			i.inv().unwrap().value().coeffs(),
+			coefficients![r(-1), r(0)]
+		);
+		assert_eq!(i.pow(2), -C::one());
+		// x³ reduces to -x
+		let x3 = C::new(Polynomial::new(coefficients![r(1), r(0), r(0), r(0)]));
+		assert_eq!(x3, -i);
+	}
+}
@@ -0,0 +1,265 @@
+use crate::Polynomial;
+
+use core::ops::{AddAssign, Div, Mul, ShrAssign, SubAssign};
+use num::{Integer, One, Zero};
+use smallvec::{smallvec, SmallVec};
+use std::collections::VecDeque;
+
+// linear recurrences aₙ = -(c₁aₙ₋₁ + … + c_Laₙ₋_L) are described by their connection polynomial
+// C(x) = 1 + c₁x + … + c_Lx^L and their length L, which may exceed the order of C(x)
+impl<T> Polynomial<T> {
+	// returns the minimal connection polynomial and the linear complexity L of the sequence
+	// (operator bounds are by value, as the usual higher-ranked bounds on references would have to be
+	// resolved before T can be inferred from the argument, causing type inference to overflow)
+	pub fn berlekamp_massey(sequence: &[T]) -> (Self, usize)
+	where
+		T: Zero + One + Clone + AddAssign + SubAssign + Mul<Output = T> + Div<Output = T>,
+	{
+		let mut c: SmallVec<[T; 8]> = smallvec![T::one()];
+		let mut b = c.clone();
+		let mut l = 0;
+		let mut m = 1;
+		let mut last_discrepancy = T::one();
+
+		for (n, s) in sequence.iter().enumerate() {
+			let mut d = s.clone();
+			for (c, s) in c.iter().skip(1).take(l).zip(sequence[..n].iter().rev()) {
+				d += c.clone() * s.clone();
+			}
+			if d.is_zero() {
+				m += 1;
+				continue;
+			}
+
+			let scale = d.clone() / last_discrepancy.clone();
+			let previous = if 2 * l <= n { Some(c.clone()) } else { None };
+			while c.len() < b.len() + m {
+				c.push(T::zero());
+			}
+			for (i, v) in b.iter().enumerate() {
+				c[i + m] -= scale.clone() * v.clone();
+			}
+			if let Some(previous) = previous {
+				l = n + 1 - l;
+				b = previous;
+				last_discrepancy = d;
+				m = 1;
+			} else {
+				m += 1;
+			}
+		}
+
+		(Self::new_reversed(c), l)
+	}
+
+	// generates the sequence starting with the given initial terms, whose count is the length of the recurrence
+	pub fn generate_sequence(&self, initial: &[T]) -> RecurrenceSequence<T>
+	where
+		T: Zero + One + Clone + PartialEq,
+	{
+		assert!(self.rev_coeffs[0].is_one());
+		assert!(self.order() as usize <= initial.len());
+		let connection = (1..=initial.len())
+			.map(|i| self.rev_coeffs.get(i).cloned().unwrap_or_else(T::zero))
+			.collect();
+		RecurrenceSequence {
+			connection,
+			window: initial.iter().cloned().collect(),
+		}
+	}
+
+	// computes the n-th term of the sequence starting with the given initial terms (Fiduccia's algorithm)
+	// as the dot product of the initial terms and the coefficients of xⁿ mod the characteristic polynomial
+	pub fn nth_term<E>(&self, initial: &[T], n: E) -> T
+	where
+		T: Zero + One + Clone + PartialEq + AddAssign + for<'r> AddAssign<&'r T> + SubAssign,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+		E: Integer + ShrAssign<usize>,
+	{
+		assert!(self.rev_coeffs[0].is_one());
+		let l = initial.len();
+		assert!(self.order() as usize <= l);
+
+		// χ(x) = xᴸ C(1/x)
+		let characteristic = Self::new_reversed(
+			(0..=l)
+				.map(|i| self.rev_coeffs.get(l - i).cloned().unwrap_or_else(T::zero))
+				.collect(),
+		);
+		let x = Self::new_reversed(smallvec![T::zero(), T::one()]);
+		let remainder = x.pow_mod(n, &characteristic);
+
+		let mut ret = T::zero();
+		for (r, a) in remainder.rev_coeffs.iter().zip(initial.iter()) {
+			ret += r * a;
+		}
+		ret
+	}
+}
+
+pub struct RecurrenceSequence<T> {
+	// c₁, …, c_L
+	connection: SmallVec<[T; 8]>,
+	// the next L terms, oldest first
+	window: VecDeque<T>,
+}
+
+impl<T> Iterator for RecurrenceSequence<T>
+where
+	T: Zero + SubAssign,
+	for<'l, 'r> &'l T: Mul<&'r T, Output = T>,
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<T> {
+		let mut next = T::zero();
+		for (c, a) in self.connection.iter().zip(self.window.iter().rev()) {
+			next -= c * a;
+		}
+		if self.window.is_empty() {
+			return Some(next);
+		}
+		self.window.push_back(next);
+		self.window.pop_front()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use num::{BigInt, BigRational};
+
+	fn r(v: i64) -> BigRational {
+		BigRational::from_integer(BigInt::from(v))
+	}
+
+	#[test]
+	fn test_berlekamp_massey_fibonacci() {
+		let sequence: Vec<_> = [0, 1, 1, 2, 3, 5, 8, 13, 21]
+			.iter()
+			.map(|&v| r(v))
+			.collect();
+		let (connection, l) = Polynomial::berlekamp_massey(&sequence);
+		assert_eq!(l, 2);
+		// 1 - x - x²
+		assert_eq!(connection.coeffs(), coefficients![r(-1), r(-1), r(1)]);
+
+		let generated: Vec<_> = connection
+			.generate_sequence(&sequence[..2])
+			.take(12)
+			.collect();
+		let expected: Vec<_> = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89]
+			.iter()
+			.map(|&v| r(v))
+			.collect();
+		assert_eq!(generated, expected);
+	}
+
+	#[test]
+	fn test_berlekamp_massey_rational() {
+		// aₙ = 2ⁿ + 3ⁿ/2 satisfies the recurrence with C(x) = (1 - 2x)(1 - 3x)
+		let sequence: Vec<_> = (0..10)
+			.map(|n| r(2i64.pow(n)) + r(3i64.pow(n)) / r(2))
+			.collect();
+		let (connection, l) = Polynomial::berlekamp_massey(&sequence);
+		assert_eq!(l, 2);
+		assert_eq!(connection.coeffs(), coefficients![r(6), r(-5), r(1)]);
+		let generated: Vec<_> = connection
+			.generate_sequence(&sequence[..2])
+			.take(10)
+			.collect();
+		assert_eq!(generated, sequence);
+	}
+
+	#[test]
+	fn test_berlekamp_massey_lfsr() {
+		type F2 = ModInt<2>;
+		// maximum length sequence of the primitive connection polynomial 1 + x + x⁴
+		let connection = Polynomial::<F2>::from_bits(0b10011);
+		let initial = [F2::new(1), F2::new(0), F2::new(0), F2::new(0)];
+		let sequence: Vec<_> = connection.generate_sequence(&initial).take(30).collect();
+		assert_eq!(&sequence[..15], &sequence[15..]);
+
+		let (recovered, l) = Polynomial::berlekamp_massey(&sequence);
+		assert_eq!(l, 4);
+		assert_eq!(recovered, connection);
+	}
+
+	#[test]
+	fn test_nth_term() {
+		type F = ModInt<1_000_000_007>;
+		let f = |v| F::new(v);
+
+		// Fibonacci numbers
+		let connection = Polynomial::new(coefficients![-f(1), -f(1), f(1)]);
+		let initial = [f(0), f(1)];
+		for (n, expected) in connection.generate_sequence(&initial).take(100).enumerate() {
+			assert_eq!(connection.nth_term(&initial, n as u64), expected);
+		}
+
+		// F(2n) = F(n)(2F(n + 1) - F(n))
+		let n = 1_000_000_000_000_000_000u64;
+		let fib_n = connection.nth_term(&initial, n);
+		let fib_n1 = connection.nth_term(&initial, n + 1);
+		let fib_2n = connection.nth_term(&initial, 2 * n);
+		assert_eq!(fib_2n, fib_n * (f(2) * fib_n1 - fib_n));
+
+		// aₙ = 3ⁿ for n = 2¹⁰⁰
+		let connection = Polynomial::new(coefficients![-f(3), f(1)]);
+		let n = num::BigUint::from(1u32) << 100usize;
+		let mut expected = f(3);
+		for _ in 0..100 {
+			expected = expected * expected;
+		}
+		assert_eq!(connection.nth_term(&[f(1)], n), expected);
+	}
+
+	#[test]
+	fn test_nth_term_rational() {
+		// the length of the recurrence may exceed the order of the connection polynomial
+		let sequence = [r(1), r(0), r(0)];
+		let (connection, l) = Polynomial::berlekamp_massey(&sequence);
+		assert_eq!(connection.nth_term(&sequence[..l], 0u32), r(1));
+		assert_eq!(connection.nth_term(&sequence[..l], 5u32), r(0));
+
+		let sequence: Vec<_> = (0..10).map(|n| r(2i64.pow(n)) + r(n as i64)).collect();
+		let (connection, l) = Polynomial::berlekamp_massey(&sequence);
+		assert_eq!(l, 3);
+		assert_eq!(
+			connection.nth_term(&sequence[..l], 40u32),
+			r(2i64.pow(40) + 40)
+		);
+	}
+
+	#[test]
+	fn test_berlekamp_massey_degenerate() {
+		let (connection, l) = Polynomial::berlekamp_massey(&[r(0), r(0), r(0)]);
+		assert_eq!(l, 0);
+		assert!(num::One::is_one(&connection));
+		assert_eq!(
+			connection
+				.generate_sequence(&[])
+				.take(2)
+				.collect::<Vec<_>>(),
+			vec![r(0), r(0)]
+		);
+
+		// the linear complexity of 0, 0, 0, 1 is 4
+		let sequence = [r(0), r(0), r(0), r(1)];
+		let (connection, l) = Polynomial::berlekamp_massey(&sequence);
+		assert_eq!(l, 4);
+		let generated: Vec<_> = connection.generate_sequence(&sequence).take(4).collect();
+		assert_eq!(generated, sequence);
+
+		// the length of the recurrence may exceed the order of the connection polynomial
+		let sequence = [r(1), r(0), r(0)];
+		let (connection, l) = Polynomial::berlekamp_massey(&sequence);
+		assert_eq!(l, 1);
+		assert_eq!(connection.order(), 0);
+		let generated: Vec<_> = connection
+			.generate_sequence(&sequence[..1])
+			.take(3)
+			.collect();
+		assert_eq!(generated, sequence);
+	}
+}
@@ -0,0 +1,273 @@
+use crate::{Gf256, Polynomial};
+
+use num::{One, Zero};
+
+// Reed–Solomon codes over GF(2⁸) with primitive element α = x and first consecutive root α⁰
+// (the convention used by, e.g., QR codes)
+//
+// codewords are byte slices where the first byte is the coefficient of the highest power of x,
+// i.e., in the same order as Polynomial::new expects
+#[derive(Clone, Debug)]
+pub struct ReedSolomon {
+	parity: usize,
+	generator: Polynomial<Gf256>,
+	// αᵏ for k in 0..255
+	powers: Vec<Gf256>,
+}
+
+fn to_field(byte: u8) -> Gf256 {
+	Gf256::from_bits(u64::from(byte))
+}
+
+// in characteristic 2, the formal derivative only keeps the odd powers
+fn formal_derivative(poly: &Polynomial<Gf256>) -> Polynomial<Gf256> {
+	let rev_coeffs = poly
+		.rev_coeffs
+		.iter()
+		.enumerate()
+		.skip(1)
+		.map(|(k, v)| if k % 2 == 1 { v.clone() } else { Gf256::zero() })
+		.collect();
+	Polynomial::new_reversed(rev_coeffs)
+}
+
+fn truncate(poly: &Polynomial<Gf256>, len: usize) -> Polynomial<Gf256> {
+	Polynomial::new_reversed(poly.rev_coeffs.iter().take(len).cloned().collect())
+}
+
+impl ReedSolomon {
+	pub fn new(parity: usize) -> Self {
+		assert!(parity > 0 && parity < 255);
+		let alpha = Gf256::from_bits(2);
+		let mut powers = Vec::with_capacity(255);
+		powers.push(Gf256::one());
+		for k in 1..255 {
+			powers.push(&powers[k - 1] * &alpha);
+		}
+
+		let mut generator = Polynomial::<Gf256>::one();
+		for power in powers.iter().take(parity) {
+			generator *= Polynomial::new(smallvec::smallvec![Gf256::one(), power.clone()]);
+		}
+		Self {
+			parity,
+			generator,
+			powers,
+		}
+	}
+
+	fn alpha_pow(&self, exp: usize) -> Gf256 {
+		self.powers[exp % 255].clone()
+	}
+
+	pub fn parity(&self) -> usize {
+		self.parity
+	}
+
+	pub fn generator(&self) -> &Polynomial<Gf256> {
+		&self.generator
+	}
+
+	pub fn encode(&self, data: &[u8]) -> Vec<u8> {
+		assert!(data.len() + self.parity <= 255);
+		let shifted = Polynomial::new(
+			data.iter()
+				.cloned()
+				.map(to_field)
+				.chain((0..self.parity).map(|_| Gf256::zero()))
+				.collect(),
+		);
+		let (_, remainder) = shifted.div_rem(&self.generator);
+
+		let mut codeword = data.to_vec();
+		codeword.resize(data.len() + self.parity, 0);
+		for (k, v) in remainder.rev_coeffs.iter().enumerate() {
+			codeword[data.len() + self.parity - 1 - k] = v.to_bits() as u8;
+		}
+		codeword
+	}
+
+	pub fn syndromes(&self, codeword: &[u8]) -> Vec<Gf256> {
+		let poly = Polynomial::new(codeword.iter().cloned().map(to_field).collect());
+		(0..self.parity)
+			.map(|j| poly.eval(self.alpha_pow(j)))
+			.collect()
+	}
+
+	// corrects errors and erasures (given as indices into codeword) in place
+	// returns the number of corrected symbols or None if the codeword could not be decoded,
+	// which is guaranteed to be detected only if 2 · errors + erasures ≤ parity
+	pub fn decode(&self, codeword: &mut [u8], erasures: &[usize]) -> Option<usize> {
+		let n = codeword.len();
+		assert!(n > self.parity && n <= 255);
+		assert!(erasures.iter().all(|&i| i < n));
+		if erasures.len() > self.parity {
+			return None;
+		}
+
+		let syndromes = self.syndromes(codeword);
+		if syndromes.iter().all(Zero::is_zero) {
+			return Some(0);
+		}
+		let syndromes = Polynomial::new_reversed(syndromes.into_iter().collect());
+
+		// erasure locator Γ(x) = ∏ (1 - Xₖx)
+		let mut erasure_locator = Polynomial::<Gf256>::one();
+		for &i in erasures {
+			erasure_locator *=
+				Polynomial::new(smallvec::smallvec![self.alpha_pow(n - 1 - i), Gf256::one()]);
+		}
+
+		// the Forney syndromes satisfy a linear recurrence given by the error locator
+		let forney = truncate(&(&syndromes * &erasure_locator), self.parity);
+		let forney: Vec<_> = (erasures.len()..self.parity)
+			.map(|j| {
+				forney
+					.rev_coeffs
+					.get(j)
+					.cloned()
+					.unwrap_or_else(Gf256::zero)
+			})
+			.collect();
+		let (error_locator, num_errors) = Polynomial::berlekamp_massey(&forney);
+		if 2 * num_errors + erasures.len() > self.parity {
+			return None;
+		}
+
+		// Chien search for the roots X⁻¹ of the errata locator Ψ(x) = Λ(x)Γ(x)
+		let locator = &error_locator * &erasure_locator;
+		let positions: Vec<_> = (0..n)
+			.filter(|&i| locator.eval(self.alpha_pow(255 - (n - 1 - i))).is_zero())
+			.collect();
+		if positions.len() != locator.order() as usize {
+			return None;
+		}
+
+		// Forney's algorithm for the errata values
+		let evaluator = truncate(&(&syndromes * &locator), self.parity);
+		let derivative = formal_derivative(&locator);
+		for &i in positions.iter() {
+			let x = self.alpha_pow(n - 1 - i);
+			let x_inv = self.alpha_pow(255 - (n - 1 - i));
+			let value = &(&x * &evaluator.eval(x_inv.clone())) / &derivative.eval(x_inv);
+			codeword[i] ^= value.to_bits() as u8;
+		}
+
+		if self.syndromes(codeword).iter().all(Zero::is_zero) {
+			Some(positions.len())
+		} else {
+			None
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	// simple deterministic byte source for test data
+	fn bytes(seed: u32, len: usize) -> Vec<u8> {
+		let mut state = seed;
+		(0..len)
+			.map(|_| {
+				state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+				(state >> 16) as u8
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_encode_qr() {
+		// "HELLO WORLD" as a version 1-M QR code
+		let data = [
+			32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17,
+		];
+		let rs = ReedSolomon::new(10);
+		let codeword = rs.encode(&data);
+		assert_eq!(&codeword[..16], &data);
+		assert_eq!(
+			&codeword[16..],
+			&[196, 35, 39, 119, 235, 215, 231, 226, 93, 23]
+		);
+		assert!(rs.syndromes(&codeword).iter().all(num::Zero::is_zero));
+	}
+
+	#[test]
+	fn test_decode_errors() {
+		let rs = ReedSolomon::new(32);
+		let data = bytes(1, 223);
+		let codeword = rs.encode(&data);
+
+		let mut received = codeword.clone();
+		assert_eq!(rs.decode(&mut received, &[]), Some(0));
+
+		for k in (0..=16).step_by(4) {
+			let mut received = codeword.clone();
+			for (i, e) in bytes(k as u32, k).into_iter().enumerate() {
+				received[i * 15 + 3] ^= e | 1;
+			}
+			assert_eq!(rs.decode(&mut received, &[]), Some(k));
+			assert_eq!(received, codeword);
+		}
+	}
+
+	#[test]
+	fn test_decode_erasures() {
+		let rs = ReedSolomon::new(10);
+		let data = bytes(2, 40);
+		let codeword = rs.encode(&data);
+
+		let mut received = codeword.clone();
+		let erasures: Vec<_> = (0..10).map(|i| i * 5).collect();
+		for &i in erasures.iter() {
+			received[i] = 0;
+		}
+		assert!(rs.decode(&mut received, &erasures).is_some());
+		assert_eq!(received, codeword);
+
+		let erasures: Vec<_> = (0..11).collect();
+		assert_eq!(rs.decode(&mut received, &erasures), None);
+	}
+
+	#[test]
+	fn test_decode_errors_and_erasures() {
+		let rs = ReedSolomon::new(16);
+		let data = bytes(3, 100);
+		let codeword = rs.encode(&data);
+
+		// 2 · 5 errors + 6 erasures = 16 parity symbols
+		let mut received = codeword.clone();
+		let erasures = [0, 7, 50, 51, 99, 115];
+		for &i in erasures.iter() {
+			received[i] ^= 0x5a;
+		}
+		for &i in [3, 20, 64, 100, 110].iter() {
+			received[i] ^= 0xff;
+		}
+		assert_eq!(rs.decode(&mut received, &erasures), Some(11));
+		assert_eq!(received, codeword);
+	}
+
+	#[test]
+	fn test_decode_too_many_errors() {
+		let rs = ReedSolomon::new(4);
+		let data = bytes(4, 20);
+		let codeword = rs.encode(&data);
+
+		// three errors exceed the correction capability of four parity symbols, so the decoder must
+		// either fail or miscorrect to a different codeword
+		for (k, positions) in [[0, 1, 2], [3, 10, 21], [5, 6, 23], [1, 12, 17]]
+			.iter()
+			.enumerate()
+		{
+			let mut received = codeword.clone();
+			for (&i, e) in positions.iter().zip(bytes(k as u32 + 10, 3)) {
+				received[i] ^= e | 1;
+			}
+			if rs.decode(&mut received, &[]).is_some() {
+				assert_ne!(received, codeword);
+				assert!(rs.syndromes(&received).iter().all(num::Zero::is_zero));
+			}
+		}
+	}
+}
@@ -0,0 +1,247 @@
+use crate::fit::unscale;
+use crate::linalg::{least_squares, Matrix};
+use crate::{ChebyshevPolynomial, Polynomial};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApproximationError {
+	// |f(x) - p(x)|
+	Absolute,
+	// |f(x) - p(x)| / |f(x)|, f must not vanish on the interval
+	Relative,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemezResult {
+	pub polynomial: Polynomial<f64>,
+	// maximum error on the interval
+	pub error: f64,
+	// the points at which the error equioscillates, i.e., attains its maximum with alternating signs
+	pub extrema: Vec<f64>,
+	pub iterations: usize,
+}
+
+const MAX_ITERATIONS: usize = 100;
+const TOLERANCE: f64 = 1e-6;
+// number of samples per reference point used to locate the extrema of the error
+const OVERSAMPLING: usize = 64;
+
+// maximizes |e| on [lo, hi] using golden-section search
+fn refine<E: Fn(f64) -> f64>(error: &E, lo: f64, hi: f64) -> f64 {
+	let ratio = (5f64.sqrt() - 1.0) / 2.0;
+	let (mut lo, mut hi) = (lo, hi);
+	for _ in 0..60 {
+		let l = hi - ratio * (hi - lo);
+		let h = lo + ratio * (hi - lo);
+		if error(l).abs() >= error(h).abs() {
+			hi = h;
+		} else {
+			lo = l;
+		}
+	}
+	(lo + hi) / 2.0
+}
+
+impl Polynomial<f64> {
+	// computes the polynomial of the given degree minimizing the maximum error to f on [a, b] using
+	// the Remez exchange algorithm, starting from the Chebyshev extrema as reference
+	//
+	// internally, the polynomial is represented in the Chebyshev basis on [-1, 1] for stability
+	// the iteration stops once the error levels on the reference agree to a relative tolerance of 10⁻⁶
+	// returns None if the linear system for the reference becomes singular, the error does not
+	// alternate sufficiently often, which happens for instance if f is not continuous, or the error
+	// levels do not agree after 100 iterations
+	pub fn remez<F: Fn(f64) -> f64>(
+		f: F,
+		a: f64,
+		b: f64,
+		degree: usize,
+		kind: ApproximationError,
+	) -> Option<RemezResult> {
+		assert!(a < b);
+		let center = (a + b) / 2.0;
+		let half_width = (b - a) / 2.0;
+		let g = |t: f64| f(center + half_width * t);
+		let weight = |t: f64| match kind {
+			ApproximationError::Absolute => 1.0,
+			ApproximationError::Relative => 1.0 / g(t).abs(),
+		};
+
+		let n = degree + 2;
+		let mut reference: Vec<f64> = (0..n)
+			.map(|i| -(std::f64::consts::PI * i as f64 / (n - 1) as f64).cos())
+			.collect();
+		let samples = OVERSAMPLING * n;
+		let grid: Vec<f64> = (0..=samples)
+			.map(|k| -(std::f64::consts::PI * k as f64 / samples as f64).cos())
+			.collect();
+
+		let mut chebyshev;
+		let mut iterations = 0;
+		let error = loop {
+			iterations += 1;
+
+			// solve Σ cⱼTⱼ(tᵢ) + (-1)ⁱ E / w(tᵢ) = g(tᵢ) for the coefficients and the levelled error E
+			let mut matrix = Matrix::zeros(n, n);
+			let mut rhs = vec![0.0; n];
+			for (i, &t) in reference.iter().enumerate() {
+				let (mut t0, mut t1) = (1.0, t);
+				for j in 0..n - 1 {
+					*matrix.get_mut(i, j) = t0;
+					let t2 = 2.0 * t * t1 - t0;
+					t0 = t1;
+					t1 = t2;
+				}
+				let w = weight(t);
+				if !w.is_finite() {
+					return None;
+				}
+				*matrix.get_mut(i, n - 1) = if i % 2 == 0 { 1.0 } else { -1.0 } / w;
+				rhs[i] = g(t);
+			}
+			let (solution, _) = least_squares(&matrix, &rhs)?;
+			chebyshev = ChebyshevPolynomial::new(solution[..n - 1].iter().cloned().collect());
+
+			let e = |t: f64| (g(t) - chebyshev.eval(&t)) * weight(t);
+			let values: Vec<f64> = grid.iter().map(|&t| e(t)).collect();
+			let scale = values.iter().fold(0f64, |s, v| s.max(v.abs()));
+			if scale <= 64.0 * f64::EPSILON * grid.iter().fold(0f64, |s, &t| s.max(g(t).abs())) {
+				// f is (numerically) a polynomial of the given degree
+				break scale;
+			}
+
+			// the extremum of each run of equal sign on the grid, refined between its neighbors
+			let mut extrema: Vec<f64> = Vec::new();
+			let mut k = 0;
+			while k < grid.len() {
+				let sign = values[k] >= 0.0;
+				let mut best = k;
+				while k < grid.len() && (values[k] >= 0.0) == sign {
+					if values[k].abs() > values[best].abs() {
+						best = k;
+					}
+					k += 1;
+				}
+				let lo = grid[best.saturating_sub(1)];
+				let hi = grid[(best + 1).min(grid.len() - 1)];
+				let t = refine(&e, lo, hi);
+				extrema.push(if e(t).abs() > values[best].abs() {
+					t
+				} else {
+					grid[best]
+				});
+			}
+			if extrema.len() < n {
+				return None;
+			}
+			// the runs alternate in sign, so dropping the smaller of the outermost extrema keeps them
+			// alternating and retains the global maximum
+			while extrema.len() > n {
+				if e(extrema[0]).abs() < e(*extrema.last().unwrap()).abs() {
+					extrema.remove(0);
+				} else {
+					extrema.pop();
+				}
+			}
+
+			let levels: Vec<f64> = extrema.iter().map(|&t| e(t).abs()).collect();
+			let max = levels.iter().fold(0f64, |s, &v| s.max(v));
+			let min = levels.iter().fold(f64::INFINITY, |s, &v| s.min(v));
+			reference = extrema;
+			if max - min <= TOLERANCE * max {
+				break max.max(scale);
+			}
+			if iterations == MAX_ITERATIONS {
+				return None;
+			}
+		};
+
+		Some(RemezResult {
+			polynomial: unscale(&chebyshev.to_polynomial(), center, half_width),
+			error,
+			extrema: reference.iter().map(|&t| center + half_width * t).collect(),
+			iterations,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn assert_equioscillates<F: Fn(f64) -> f64>(result: &RemezResult, error: F) {
+		let mut sign = 0.0;
+		for &x in result.extrema.iter() {
+			let e = error(x);
+			assert!((e.abs() - result.error).abs() <= 1e-5 * result.error);
+			assert!(e * sign <= 0.0);
+			sign = e;
+		}
+	}
+
+	#[test]
+	fn test_remez_exp() {
+		let result =
+			Polynomial::remez(f64::exp, -1.0, 1.0, 3, ApproximationError::Absolute).unwrap();
+		// the minimax error of a cubic approximation of exp on [-1, 1] is about 5.53 · 10⁻³
+		assert!((result.error - 5.53e-3).abs() < 1e-5);
+		assert_eq!(result.extrema.len(), 5);
+		assert_eq!(result.extrema[0], -1.0);
+		assert_eq!(result.extrema[4], 1.0);
+		let p = result.polynomial.clone();
+		assert_equioscillates(&result, |x| x.exp() - p.eval(x));
+		for k in 0..=100 {
+			let x = f64::from(k) / 50.0 - 1.0;
+			let y: f64 = p.eval(x);
+			assert!((x.exp() - y).abs() <= result.error * (1.0 + 1e-5));
+		}
+	}
+
+	#[test]
+	fn test_remez_abs() {
+		// the best quadratic approximation of |x| on [-1, 1] is x² + 1/8
+		let result =
+			Polynomial::remez(f64::abs, -1.0, 1.0, 2, ApproximationError::Absolute).unwrap();
+		assert!((result.error - 0.125).abs() < 1e-6);
+		let coeffs = result.polynomial.coeffs();
+		assert!((coeffs[0] - 1.0).abs() < 1e-5);
+		assert!(coeffs[1].abs() < 1e-5);
+		assert!((coeffs[2] - 0.125).abs() < 1e-5);
+	}
+
+	#[test]
+	fn test_remez_relative() {
+		let result =
+			Polynomial::remez(f64::exp, 0.0, 1.0, 2, ApproximationError::Relative).unwrap();
+		let p = result.polynomial.clone();
+		assert_equioscillates(&result, |x| (x.exp() - p.eval(x)) / x.exp());
+		// better than truncated Taylor series or the absolute error approximation in the relative sense
+		let absolute =
+			Polynomial::remez(f64::exp, 0.0, 1.0, 2, ApproximationError::Absolute).unwrap();
+		let q = absolute.polynomial;
+		let relative_error_of_absolute = (0..=100)
+			.map(|k| f64::from(k) / 100.0)
+			.map(|x: f64| ((x.exp() - q.eval(x)) / x.exp()).abs())
+			.fold(0f64, f64::max);
+		assert!(result.error < relative_error_of_absolute);
+	}
+
+	#[test]
+	fn test_remez_polynomial() {
+		// polynomials of at most the given degree are reproduced
+		let poly = Polynomial::new(coefficients![0.5f64, -2.0, 1.0]);
+		let result =
+			Polynomial::remez(|x| poly.eval(x), 1.0, 3.0, 3, ApproximationError::Absolute).unwrap();
+		assert!(result.error < 1e-14);
+		let coeffs = result.polynomial.coeffs();
+		for (a, b) in coeffs.iter().rev().zip(poly.coeffs().iter().rev()) {
+			assert!((a - b).abs() < 1e-12);
+		}
+	}
+
+	#[test]
+	fn test_remez_no_convergence() {
+		// the error levels of a line through pseudo-random noise do not agree within the iteration limit
+		let noise = |x: f64| ((x * 12345.678).sin() * 43758.5453).fract();
+		assert!(Polynomial::remez(noise, -1.0, 1.0, 1, ApproximationError::Absolute).is_none());
+	}
+}
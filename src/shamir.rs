@@ -0,0 +1,121 @@
+use crate::Polynomial;
+
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Sub};
+use num::{FromPrimitive, One, Zero};
+
+// Shamir's threshold secret sharing over a finite field such as ModInt<P>
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Share<T> {
+	pub x: T,
+	pub y: T,
+}
+
+impl<T> Share<T> {
+	// splits the secret into count shares, any threshold of which suffice for reconstruction
+	// random must yield uniformly distributed, cryptographically secure field elements
+	pub fn split<R>(secret: T, threshold: usize, count: usize, random: R) -> Vec<Self>
+	where
+		T: Zero + One + Clone + FromPrimitive + AddAssign + for<'r> MulAssign<&'r T>,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T>,
+		R: FnMut() -> T,
+	{
+		assert!(threshold >= 1 && threshold <= count);
+		let mut random = random;
+
+		// the secret is the constant coefficient of a random polynomial of order threshold - 1
+		let rev_coeffs = core::iter::once(secret)
+			.chain((1..threshold).map(|_| random()))
+			.collect();
+		let polynomial = Polynomial::new_reversed(rev_coeffs);
+
+		(1..=count)
+			.map(|i| {
+				let x = T::from_usize(i).unwrap();
+				// the field must be large enough to provide distinct non-zero evaluation points
+				assert!(!x.is_zero());
+				let y = polynomial.eval(x.clone());
+				Self { x, y }
+			})
+			.collect()
+	}
+
+	// Lagrange interpolation at zero, the shares must have distinct x
+	pub fn reconstruct(shares: &[Self]) -> T
+	where
+		T: Zero
+			+ One
+			+ Clone
+			+ Add<Output = T>
+			+ Sub<Output = T>
+			+ Mul<Output = T>
+			+ Div<Output = T>,
+	{
+		assert!(!shares.is_empty());
+		let mut secret = T::zero();
+		for (j, share_j) in shares.iter().enumerate() {
+			let mut numerator = T::one();
+			let mut denominator = T::one();
+			for (m, share_m) in shares.iter().enumerate() {
+				if m != j {
+					numerator = numerator * share_m.x.clone();
+					denominator = denominator * (share_m.x.clone() - share_j.x.clone());
+				}
+			}
+			assert!(!denominator.is_zero());
+			secret = secret + share_j.y.clone() * numerator / denominator;
+		}
+		secret
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	type F = ModInt<2_305_843_009_213_693_951>; // 2⁶¹ - 1
+
+	// deterministic stand-in for a secure random number generator
+	fn random(seed: u64) -> impl FnMut() -> F {
+		let mut state = seed;
+		move || {
+			state = state
+				.wrapping_mul(6_364_136_223_846_793_005)
+				.wrapping_add(1_442_695_040_888_963_407);
+			F::new(state >> 3)
+		}
+	}
+
+	#[test]
+	fn test_split_reconstruct() {
+		let secret = F::new(1_234_567_890_123);
+		let shares = Share::<F>::split(secret, 3, 5, random(1));
+		assert_eq!(shares.len(), 5);
+
+		for i in 0..5 {
+			for j in (i + 1)..5 {
+				for k in (j + 1)..5 {
+					let subset = [shares[i].clone(), shares[k].clone(), shares[j].clone()];
+					assert_eq!(Share::reconstruct(&subset), secret);
+				}
+			}
+		}
+		assert_eq!(Share::reconstruct(&shares), secret);
+		assert_ne!(Share::reconstruct(&shares[..2]), secret);
+	}
+
+	#[test]
+	fn test_split_threshold_one() {
+		let secret = F::new(42);
+		let shares = Share::<F>::split(secret, 1, 3, random(2));
+		for share in shares.iter() {
+			assert_eq!(share.y, secret);
+			assert_eq!(Share::reconstruct(core::slice::from_ref(share)), secret);
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "assertion failed: !x.is_zero()")]
+	fn test_split_field_too_small() {
+		let _ = Share::<ModInt<5>>::split(ModInt::new(1), 2, 5, || ModInt::new(3));
+	}
+}
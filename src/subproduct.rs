@@ -0,0 +1,221 @@
+use crate::Polynomial;
+
+use core::ops::{AddAssign, Div, Mul, Neg, SubAssign};
+use num::{FromPrimitive, One, Zero};
+use smallvec::smallvec;
+
+// subproduct tree of the polynomials x - xᵢ for the points xᵢ, where each node is the product of its
+// children and the root is the master polynomial ∏ (x - xᵢ)
+#[derive(Clone, Debug)]
+pub struct SubproductTree<T> {
+	points: Vec<T>,
+	// levels[0] holds the leaves, a node without sibling is carried over to the next level unchanged
+	levels: Vec<Vec<Polynomial<T>>>,
+}
+
+impl<T> SubproductTree<T> {
+	pub fn new(points: &[T]) -> Self
+	where
+		T: Zero + One + Clone + AddAssign + Neg<Output = T>,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T>,
+	{
+		assert!(!points.is_empty());
+		let leaves: Vec<_> = points
+			.iter()
+			.map(|x| Polynomial::new_reversed(smallvec![-x.clone(), T::one()]))
+			.collect();
+		let mut levels = vec![leaves];
+		while levels.last().unwrap().len() > 1 {
+			let next = levels
+				.last()
+				.unwrap()
+				.chunks(2)
+				.map(|pair| match pair {
+					[lhs, rhs] => lhs * rhs,
+					_ => pair[0].clone(),
+				})
+				.collect();
+			levels.push(next);
+		}
+		Self {
+			points: points.to_vec(),
+			levels,
+		}
+	}
+
+	pub fn points(&self) -> &[T] {
+		&self.points
+	}
+
+	// the master polynomial ∏ (x - xᵢ)
+	pub fn root(&self) -> &Polynomial<T> {
+		&self.levels.last().unwrap()[0]
+	}
+
+	// evaluates the polynomial at all points by reducing it modulo the nodes from the root down to
+	// the leaves, where p mod (x - xᵢ) = p(xᵢ)
+	pub fn eval(&self, poly: &Polynomial<T>) -> Vec<T>
+	where
+		T: Zero + Clone + for<'r> AddAssign<&'r T> + SubAssign,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		let mut remainders = vec![poly.div_rem(self.root()).1];
+		for level in self.levels.iter().rev().skip(1) {
+			remainders = level
+				.iter()
+				.enumerate()
+				.map(|(i, node)| remainders[i / 2].div_rem(node).1)
+				.collect();
+		}
+		remainders
+			.into_iter()
+			.map(|r| r.rev_coeffs[0].clone())
+			.collect()
+	}
+
+	// returns Σ cᵢ · m(x) / (x - xᵢ) for the master polynomial m(x), combining the children of each
+	// node as l · R + r · L, where L and R are the children and l and r their combinations
+	pub fn linear_combination(&self, cs: &[T]) -> Polynomial<T>
+	where
+		T: Zero + Clone + AddAssign + for<'r> AddAssign<&'r T>,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T>,
+	{
+		assert_eq!(cs.len(), self.points.len());
+		let mut combinations: Vec<_> = cs
+			.iter()
+			.map(|c| Polynomial::new_reversed(smallvec![c.clone()]))
+			.collect();
+		for level in self.levels.iter().take(self.levels.len() - 1) {
+			combinations = combinations
+				.chunks(2)
+				.zip(level.chunks(2))
+				.map(|(combination, node)| match (combination, node) {
+					([l, r], [lhs, rhs]) => l * rhs + r * lhs,
+					_ => combination[0].clone(),
+				})
+				.collect();
+		}
+		combinations.pop().unwrap()
+	}
+
+	// returns the unique interpolant of minimal order through the points (xᵢ, yᵢ), the xᵢ must be
+	// distinct: by Lagrange's formula, it is the linear combination with cᵢ = yᵢ / m'(xᵢ)
+	pub fn interpolate(&self, ys: &[T]) -> Polynomial<T>
+	where
+		T: Zero + Clone + FromPrimitive + AddAssign + for<'r> AddAssign<&'r T> + SubAssign,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T> + Mul<T, Output = T>,
+	{
+		assert_eq!(ys.len(), self.points.len());
+		let derivative = self.root().derivative(1).unwrap();
+		let cs: Vec<_> = self
+			.eval(&derivative)
+			.iter()
+			.zip(ys.iter())
+			.map(|(d, y)| {
+				assert!(!d.is_zero());
+				y / d
+			})
+			.collect();
+		self.linear_combination(&cs)
+	}
+}
+
+impl<T> Polynomial<T> {
+	// evaluates the polynomial at all points using a subproduct tree, which is faster than repeated
+	// evaluation for many points when multiplication is fast; use SubproductTree directly to reuse
+	// the tree for several polynomials
+	pub fn eval_multipoint(&self, points: &[T]) -> Vec<T>
+	where
+		T: Zero + One + Clone + AddAssign + for<'r> AddAssign<&'r T> + SubAssign + Neg<Output = T>,
+		for<'l, 'r> &'l T: Mul<&'r T, Output = T> + Div<&'r T, Output = T>,
+	{
+		if points.is_empty() {
+			return Vec::new();
+		}
+		SubproductTree::<T>::new(points).eval(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+	use num::BigRational;
+
+	type F = ModInt<1_000_000_007>;
+
+	fn r(n: i64, d: i64) -> BigRational {
+		BigRational::new(n.into(), d.into())
+	}
+
+	#[test]
+	fn test_subproduct_tree() {
+		let points = [r(1, 1), r(2, 1), r(3, 1)];
+		let tree = SubproductTree::<BigRational>::new(&points);
+		assert_eq!(tree.points(), &points);
+		// (x - 1)(x - 2)(x - 3)
+		assert_eq!(
+			tree.root().coeffs(),
+			coefficients![r(1, 1), r(-6, 1), r(11, 1), r(-6, 1)]
+		);
+		assert_eq!(
+			SubproductTree::<F>::new(&[F::new(5)]).root().coeffs(),
+			coefficients![F::new(1), -F::new(5)]
+		);
+	}
+
+	#[test]
+	fn test_eval_multipoint_rational() {
+		let poly = Polynomial::new((1..=12).map(|k| r(k * k - 40, k)).collect());
+		let points: Vec<_> = (0..37).map(|k| r(k - 18, 7)).collect();
+		let expected: Vec<BigRational> = points.iter().map(|x| poly.eval(x.clone())).collect();
+		assert_eq!(poly.eval_multipoint(&points), expected);
+
+		// the order of the polynomial may be lower than the number of points
+		let poly = Polynomial::new(coefficients![r(2, 1), r(1, 3)]);
+		let values = poly.eval_multipoint(&points);
+		assert_eq!(values[18], r(1, 3));
+		assert!(poly.eval_multipoint(&[]).is_empty());
+	}
+
+	#[test]
+	fn test_eval_multipoint_modint() {
+		let poly = Polynomial::new((0..100u64).map(|k| F::new(k * k * k + 7)).collect());
+		let points: Vec<_> = (0..1000u64).map(|k| F::new(k * 7919)).collect();
+		let tree = SubproductTree::<F>::new(&points);
+		let expected: Vec<F> = points.iter().map(|&x| poly.eval(x)).collect();
+		assert_eq!(tree.eval(&poly), expected);
+		assert!(tree.eval(tree.root()).iter().all(num::Zero::is_zero));
+	}
+
+	#[test]
+	fn test_interpolate_rational() {
+		let points: Vec<_> = (0..17).map(|k| r(k * k - 100, k + 1)).collect();
+		let values: Vec<_> = (0..17).map(|k| r(k % 7 - 3, 5)).collect();
+		let tree = SubproductTree::<BigRational>::new(&points);
+		let poly = tree.interpolate(&values);
+		assert_eq!(poly, Polynomial::interpolate_lagrange(&points, &values));
+		assert_eq!(tree.eval(&poly), values);
+
+		// the master polynomial has the combination of all ones as derivative
+		let ones = vec![r(1, 1); 17];
+		assert_eq!(
+			tree.linear_combination(&ones),
+			tree.root().derivative(1).unwrap()
+		);
+		let tree = SubproductTree::<BigRational>::new(&[r(2, 1)]);
+		assert_eq!(
+			tree.interpolate(&[r(5, 3)]).coeffs(),
+			coefficients![r(5, 3)]
+		);
+	}
+
+	#[test]
+	fn test_interpolate_modint() {
+		let points: Vec<_> = (0..300u64).map(|k| F::new(k * k + 1)).collect();
+		let values: Vec<_> = (0..300u64).map(|k| F::new(k ^ 0x5a5a)).collect();
+		let tree = SubproductTree::<F>::new(&points);
+		let poly = tree.interpolate(&values);
+		assert_eq!(poly, Polynomial::interpolate(&points, &values));
+		assert_eq!(tree.eval(&poly), values);
+	}
+}